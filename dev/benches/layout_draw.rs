@@ -10,9 +10,16 @@ const SENTENCE: &str =
      conveying a statement, question, exclamation, or command, and consisting of a main \
      clause and sometimes one or more subordinate clauses.";
 
-fn bench_layout_and_draw(c: &mut Criterion) {
-    c.bench_function("layout & draw (exo2-otf)", |b| {
-        let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+/// Bench laying out & drawing [`SENTENCE`] using `draw` for each outlined glyph.
+fn bench_layout_draw_with(
+    c: &mut Criterion,
+    name: &str,
+    font_data: &[u8],
+    expected_coverage_sum: f32,
+    mut draw: impl FnMut(&OutlinedGlyph, &mut dyn FnMut(u32, u32, f32)),
+) {
+    c.bench_function(name, |b| {
+        let font = FontRef::try_from_slice(font_data).unwrap();
         let mut glyphs = vec![];
         let mut coverage_sum = 0.0;
 
@@ -30,38 +37,44 @@ fn bench_layout_and_draw(c: &mut Criterion) {
             glyphs
                 .drain(..)
                 .filter_map(|g| font.outline_glyph(g))
-                .for_each(|outlined| outlined.draw(|_, _, c| coverage_sum += c));
+                .for_each(|outlined| draw(&outlined, &mut |_, _, c| coverage_sum += c));
         });
 
         // sanity check that work has been done
-        assert_relative_eq!(coverage_sum, 6073.028);
+        assert_relative_eq!(coverage_sum, expected_coverage_sum);
     });
+}
 
-    c.bench_function("layout & draw (exo2-ttf)", |b| {
-        let font = FontRef::try_from_slice(EXO2_TTF).unwrap();
-        let mut glyphs = vec![];
-        let mut coverage_sum = 0.0;
-
-        b.iter(|| {
-            dev::layout_paragraph(
-                font.as_scaled(25.0),
-                point(100.0, 25.0),
-                600.0,
-                SENTENCE,
-                &mut glyphs,
-            );
-
-            coverage_sum = 0.0;
+fn bench_layout_and_draw(c: &mut Criterion) {
+    for (name, font_data, expected_coverage_sum) in [
+        ("exo2-otf", EXO2_OTF, 6073.028),
+        ("exo2-ttf", EXO2_TTF, 6069.2656),
+    ] {
+        bench_layout_draw_with(
+            c,
+            &format!("layout & draw ({name})"),
+            font_data,
+            expected_coverage_sum,
+            |outlined, o| outlined.draw(o),
+        );
 
-            glyphs
-                .drain(..)
-                .filter_map(|g| font.outline_glyph(g))
-                .for_each(|outlined| outlined.draw(|_, _, c| coverage_sum += c));
-        });
+        let mut rasterizer = Rasterizer::new(0, 0);
+        bench_layout_draw_with(
+            c,
+            &format!("layout & draw_with reused rasterizer ({name})"),
+            font_data,
+            expected_coverage_sum,
+            |outlined, o| outlined.draw_with(&mut rasterizer, o),
+        );
 
-        // sanity check that work has been done
-        assert_relative_eq!(coverage_sum, 6069.2656);
-    });
+        bench_layout_draw_with(
+            c,
+            &format!("layout & draw_with new rasterizer ({name})"),
+            font_data,
+            expected_coverage_sum,
+            |outlined, o| outlined.draw_with(&mut Rasterizer::new(0, 0), o),
+        );
+    }
 }
 
 criterion_group!(
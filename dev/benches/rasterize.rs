@@ -74,6 +74,27 @@ macro_rules! bench_accumulate {
     }};
 }
 
+/// Calls `accumulate_into` on a pre-outlined rasterizer.
+macro_rules! bench_accumulate_into {
+    ($criterion:expr, $bench_name:expr, $raster_fn:expr, $const_dimensions:expr) => {{
+        let mut target = vec![0.0; $const_dimensions.0 * $const_dimensions.1];
+
+        // call outline functions
+        let rasterizer = $raster_fn();
+        // check we got the const dimensions right
+        let (w, h) = rasterizer.dimensions();
+        assert_eq!((w, h), $const_dimensions);
+
+        $criterion.bench_function($bench_name, |b| {
+            b.iter(|| {
+                rasterizer.accumulate_into(&mut target);
+                // ensure the target has changed (and not discarded by optimization?).
+                assert!(target.iter().any(|a| *a != 0.0), "target not written to?");
+            });
+        });
+    }};
+}
+
 fn rasterize_ttf_w(c: &mut Criterion) {
     bench_draw!(c, "rasterize_ttf_w", dev::rasterize_ttf_w, (9, 8));
 }
@@ -86,6 +107,10 @@ fn accumulate_ttf_w(c: &mut Criterion) {
     bench_accumulate!(c, "accumulate_ttf_w", dev::rasterize_ttf_w, (9, 8));
 }
 
+fn accumulate_into_ttf_w(c: &mut Criterion) {
+    bench_accumulate_into!(c, "accumulate_into_ttf_w", dev::rasterize_ttf_w, (9, 8));
+}
+
 fn rasterize_ttf_tailed_e(c: &mut Criterion) {
     bench_draw!(
         c,
@@ -131,6 +156,24 @@ fn accumulate_ttf_biohazard(c: &mut Criterion) {
     );
 }
 
+fn accumulate_into_ttf_biohazard(c: &mut Criterion) {
+    bench_accumulate_into!(
+        c,
+        "accumulate_into_ttf_biohazard",
+        dev::rasterize_ttf_biohazard,
+        (294, 269)
+    );
+}
+
+fn rasterize_outline_wide_rhombus(c: &mut Criterion) {
+    bench_draw_outline!(
+        c,
+        "rasterize_outline_wide_rhombus",
+        dev::rasterize_wide_rhombus,
+        (1200, 64)
+    );
+}
+
 criterion_group!(
     name = draw_benches;
     config = Criterion::default()
@@ -141,11 +184,14 @@ criterion_group!(
     targets = rasterize_ttf_w,
         rasterize_outline_ttf_w,
         accumulate_ttf_w,
+        accumulate_into_ttf_w,
         rasterize_ttf_tailed_e,
         rasterize_otf_tailed_e,
         rasterize_ttf_biohazard,
         rasterize_outline_ttf_biohazard,
         accumulate_ttf_biohazard,
+        accumulate_into_ttf_biohazard,
+        rasterize_outline_wide_rhombus,
 );
 
 criterion_main!(draw_benches);
@@ -0,0 +1,9 @@
+/// Reads a big-endian `u16` from font `data` at `offset`.
+pub fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+/// Reads a big-endian `u32` from font `data` at `offset`.
+pub fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
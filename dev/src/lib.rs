@@ -1,5 +1,7 @@
+mod font_data;
 mod layout;
 mod ref_raster;
 
+pub use font_data::*;
 pub use layout::*;
 pub use ref_raster::*;
@@ -218,3 +218,13 @@ pub fn rasterize_ttf_tailed_e() -> Rasterizer {
     r.draw_line(point(95.25, 121.5), point(95.25, 121.5));
     r
 }
+
+/// Draw a shallow 1200x64 rhombus, every line spanning ~37 pixels per scanline.
+pub fn rasterize_wide_rhombus() -> Rasterizer {
+    let mut rasterizer = Rasterizer::new(1200, 64);
+    rasterizer.draw_line(point(0.0, 32.0), point(600.0, 0.0));
+    rasterizer.draw_line(point(600.0, 0.0), point(1200.0, 32.0));
+    rasterizer.draw_line(point(1200.0, 32.0), point(600.0, 64.0));
+    rasterizer.draw_line(point(600.0, 64.0), point(0.0, 32.0));
+    rasterizer
+}
@@ -0,0 +1,136 @@
+use ab_glyph::*;
+use dev::{read_u16, read_u32};
+use std::sync::Arc;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_TTF: &[u8] = include_bytes!("../fonts/Exo2-Light.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
+
+/// Returns a font collection of `fonts`, each with its own copy of its tables.
+fn font_collection(fonts: &[&[u8]]) -> Vec<u8> {
+    let mut ttc = b"ttcf".to_vec();
+    ttc.extend(0x0001_0000_u32.to_be_bytes());
+    ttc.extend((fonts.len() as u32).to_be_bytes());
+    let offsets_start = ttc.len();
+    ttc.resize(offsets_start + 4 * fonts.len(), 0);
+
+    for (idx, font) in fonts.iter().enumerate() {
+        let font_offset = ttc.len() as u32;
+        ttc[offsets_start + 4 * idx..][..4].copy_from_slice(&font_offset.to_be_bytes());
+
+        let num_tables = read_u16(font, 4) as usize;
+        let directory_len = 12 + 16 * num_tables;
+        let directory_start = ttc.len();
+        ttc.extend(&font[..directory_len]);
+        for table in 0..num_tables {
+            let record = 12 + 16 * table;
+            let (offset, len) = (
+                read_u32(font, record + 8) as usize,
+                read_u32(font, record + 12) as usize,
+            );
+            let new_offset = ttc.len() as u32;
+            ttc[directory_start + record + 8..][..4].copy_from_slice(&new_offset.to_be_bytes());
+            ttc.extend(&font[offset..offset + len]);
+            ttc.resize(ttc.len().next_multiple_of(4), 0);
+        }
+    }
+    ttc
+}
+
+#[test]
+fn collection_faces() {
+    let ttc = font_collection(&[DEJA_VU_MONO, EXO2_TTF, OPENS_SANS_ITALIC]);
+    let collection = FontCollection::new(ttc);
+    assert_eq!(collection.face_count(), 3);
+
+    let names: Vec<_> = collection
+        .faces()
+        .map(|font| {
+            let font = font.unwrap();
+            (font.family_name().unwrap(), font.subfamily_name().unwrap())
+        })
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("DejaVu Sans Mono".into(), "Book".into()),
+            ("Exo 2".into(), "Light".into()),
+            ("Open Sans".into(), "Italic".into()),
+        ]
+    );
+
+    // faces should behave like the individual fonts
+    for (idx, data) in [DEJA_VU_MONO, EXO2_TTF, OPENS_SANS_ITALIC]
+        .iter()
+        .enumerate()
+    {
+        let face = collection.face(idx as u32).unwrap();
+        let font = FontRef::try_from_slice(data).unwrap();
+        let id = font.glyph_id('q');
+        assert_eq!(face.glyph_id('q'), id);
+        assert_eq!(face.h_advance_unscaled(id), font.h_advance_unscaled(id));
+        assert_eq!(
+            face.outline(id).unwrap().bounds,
+            font.outline(id).unwrap().bounds
+        );
+    }
+
+    assert_eq!(
+        collection.face(3).unwrap_err(),
+        FontError::FaceIndexOutOfBounds {
+            index: 3,
+            face_count: 3
+        }
+    );
+}
+
+/// Faces should share the collection data, living beyond the collection.
+#[test]
+fn collection_shared_data() {
+    let data: Arc<[u8]> = font_collection(&[EXO2_TTF, DEJA_VU_MONO]).into();
+    let collection = FontCollection::new(Arc::clone(&data));
+    assert_eq!(collection.data().as_ptr(), data.as_ptr());
+
+    let faces: Vec<FontArc> = collection.faces().map(Result::unwrap).collect();
+    // faces share the collection reference to the data
+    assert_eq!(Arc::strong_count(&data), 2);
+    drop(collection);
+
+    for face in &faces {
+        assert_eq!(face.font_data().as_ptr(), data.as_ptr());
+    }
+    let q = faces[1]
+        .outline_glyph(faces[1].glyph_id('q').with_scale(20.0))
+        .unwrap();
+    let mut area = 0.0;
+    q.draw(|_, _, c| area += c);
+    assert!(area > 10.0, "{area}");
+
+    drop(faces);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn single_font_collection() {
+    let collection = FontCollection::new(EXO2_OTF);
+    assert_eq!(collection.face_count(), 1);
+    let font = collection.face(0).unwrap();
+    assert_eq!(font.family_name().as_deref(), Some("Exo 2"));
+    assert_eq!(font.glyph_id('s'), GlyphId(56));
+
+    let invalid = FontCollection::new(&b"not a font"[..]);
+    assert_eq!(invalid.face_count(), 1);
+    assert_eq!(invalid.face(0).unwrap_err(), FontError::UnknownMagic);
+}
+
+#[test]
+fn font_names() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    assert_eq!(font.family_name().as_deref(), Some("Exo 2"));
+    assert_eq!(font.subfamily_name().as_deref(), Some("Light"));
+
+    let font = FontArc::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    assert_eq!(font.family_name().as_deref(), Some("Open Sans"));
+    assert_eq!(font.subfamily_name().as_deref(), Some("Italic"));
+}
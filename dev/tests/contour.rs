@@ -0,0 +1,159 @@
+use ab_glyph::*;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns the points of each curve, ignoring zero length lines.
+fn curve_points(curves: &[OutlineCurve]) -> Vec<Vec<Point>> {
+    curves
+        .iter()
+        .filter_map(|curve| match *curve {
+            OutlineCurve::Line(p0, p1) if p0 == p1 => None,
+            OutlineCurve::Line(p0, p1) => Some(vec![p0, p1]),
+            OutlineCurve::Quad(p0, p1, p2) => Some(vec![p0, p1, p2]),
+            OutlineCurve::Cubic(p0, p1, p2, p3) => Some(vec![p0, p1, p2, p3]),
+        })
+        .collect()
+}
+
+#[test]
+fn contours_round_trip() {
+    for (font_data, c, contour_count) in [
+        (DEJA_VU_MONO, 'l', 1),
+        (DEJA_VU_MONO, 'B', 3),
+        (DEJA_VU_MONO, '%', 5),
+        (EXO2_OTF, 'o', 2),
+        (EXO2_OTF, 'ę', 2),
+    ] {
+        let font = FontRef::try_from_slice(font_data).unwrap();
+        let outline = font.outline(font.glyph_id(c)).unwrap();
+        let contours = outline.contours();
+        assert_eq!(contours.len(), contour_count, "{c:?}");
+        for contour in &contours {
+            assert!(contour.points[0].is_on_curve(), "{c:?}");
+        }
+
+        let from_contours = Outline::from_contours(&contours);
+        assert_eq!(
+            curve_points(&from_contours.curves),
+            curve_points(&outline.curves),
+            "{c:?}"
+        );
+        assert_eq!(from_contours.contours(), contours, "{c:?}");
+    }
+}
+
+/// Contours touching at their start points should not be merged.
+#[test]
+fn touching_contours() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let outline = font.outline(GlyphId(2141)).unwrap();
+    assert_eq!(outline.contour_starts().len(), 5);
+    let contours = outline.contours();
+    assert_eq!(contours.len(), 5);
+
+    let from_contours = Outline::from_contours(&contours);
+    assert_eq!(from_contours.contours(), contours);
+    assert_eq!(from_contours.contour_starts().len(), 5);
+}
+
+#[test]
+fn contour_point_kinds() {
+    let tt_font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let contours = tt_font.outline(tt_font.glyph_id('o')).unwrap().contours();
+    let kinds = || contours.iter().flat_map(|c| &c.points).map(|p| p.kind);
+    assert!(kinds().any(|k| k == PointKind::QuadControl));
+    assert!(!kinds().any(|k| k == PointKind::CubicControl));
+
+    let cff_font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let contours = cff_font.outline(cff_font.glyph_id('o')).unwrap().contours();
+    let kinds = || contours.iter().flat_map(|c| &c.points).map(|p| p.kind);
+    assert!(kinds().any(|k| k == PointKind::CubicControl));
+    assert!(!kinds().any(|k| k == PointKind::QuadControl));
+}
+
+/// Consecutive quadratic controls should imply on-curve points between them.
+#[test]
+fn implied_on_curve_points() {
+    let contour = Contour {
+        points: vec![
+            ContourPoint::on_curve(point(0.0, 0.0)),
+            ContourPoint::quad_control(point(0.0, 10.0)),
+            ContourPoint::quad_control(point(10.0, 10.0)),
+            ContourPoint::on_curve(point(10.0, 0.0)),
+        ],
+    };
+    assert_eq!(
+        curve_points(&contour.curves()),
+        [
+            vec![point(0.0, 0.0), point(0.0, 10.0), point(5.0, 10.0)],
+            vec![point(5.0, 10.0), point(10.0, 10.0), point(10.0, 0.0)],
+            vec![point(10.0, 0.0), point(0.0, 0.0)],
+        ]
+    );
+
+    // TrueType allows contours with only off-curve points
+    let diamond = Contour {
+        points: vec![
+            ContourPoint::quad_control(point(0.0, 0.0)),
+            ContourPoint::quad_control(point(0.0, 2.0)),
+            ContourPoint::quad_control(point(2.0, 2.0)),
+            ContourPoint::quad_control(point(2.0, 0.0)),
+        ],
+    };
+    assert_eq!(
+        curve_points(&diamond.curves()),
+        [
+            vec![point(1.0, 0.0), point(0.0, 0.0), point(0.0, 1.0)],
+            vec![point(0.0, 1.0), point(0.0, 2.0), point(1.0, 2.0)],
+            vec![point(1.0, 2.0), point(2.0, 2.0), point(2.0, 1.0)],
+            vec![point(2.0, 1.0), point(2.0, 0.0), point(1.0, 0.0)],
+        ]
+    );
+}
+
+#[test]
+fn contour_closing_curve() {
+    // starts with a control, so the first on-curve point starts the curves
+    let contour = Contour {
+        points: vec![
+            ContourPoint::cubic_control(point(-1.0, 4.0)),
+            ContourPoint::on_curve(point(0.0, 5.0)),
+            ContourPoint::on_curve(point(5.0, 5.0)),
+            ContourPoint::on_curve(point(5.0, 0.0)),
+            ContourPoint::cubic_control(point(1.0, 0.0)),
+        ],
+    };
+    assert_eq!(
+        curve_points(&contour.curves()),
+        [
+            vec![point(0.0, 5.0), point(5.0, 5.0)],
+            vec![point(5.0, 5.0), point(5.0, 0.0)],
+            vec![
+                point(5.0, 0.0),
+                point(1.0, 0.0),
+                point(-1.0, 4.0),
+                point(0.0, 5.0)
+            ],
+        ]
+    );
+
+    let outline = Outline::from_contours(&[contour]);
+    assert_eq!(
+        outline.bounds,
+        Rect {
+            min: point(-1.0, 5.0),
+            max: point(5.0, 0.0),
+        }
+    );
+}
+
+#[test]
+fn empty_contours() {
+    assert!(Contour::default().curves().is_empty());
+
+    let outline = Outline::from_contours(&[]);
+    assert!(outline.curves.is_empty());
+    assert_eq!(outline.bounds, Rect::default());
+    assert!(outline.contours().is_empty());
+}
@@ -0,0 +1,144 @@
+use ab_glyph::*;
+use approx::assert_relative_eq;
+
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
+
+/// Returns glyphs of `text` laid out in a line from `position`.
+fn layout<F: Font>(font: F, scale: f32, position: Point, text: &str) -> Vec<Glyph> {
+    let font = font.as_scaled(scale);
+    let mut caret = position;
+    text.chars()
+        .map(|c| {
+            let glyph = font.scaled_glyph(c);
+            let position = caret;
+            caret.x += font.h_advance(glyph.id);
+            Glyph { position, ..glyph }
+        })
+        .collect()
+}
+
+/// Returns the coverage of each column & the total coverage.
+fn draw_columns(decoration: &Decoration) -> (Vec<f32>, f32) {
+    let mut columns = vec![0.0; decoration.px_bounds().width() as usize];
+    decoration.draw(|x, _, c| columns[x as usize] += c);
+    let total = columns.iter().sum();
+    (columns, total)
+}
+
+#[test]
+fn decoration_metrics() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    assert_eq!(
+        font.underline_metrics_unscaled(),
+        Some(DecorationMetrics {
+            position: -75.0,
+            thickness: 50.0
+        })
+    );
+    assert_eq!(
+        font.strikeout_metrics_unscaled(),
+        Some(DecorationMetrics {
+            position: 291.0,
+            thickness: 50.0
+        })
+    );
+
+    let font = FontArc::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let scaled = font.as_scaled(30.0);
+    let v_scale = scaled.v_scale_factor();
+    let underline = scaled.underline_metrics().unwrap();
+    assert_relative_eq!(underline.position, -154.0 * v_scale);
+    assert_relative_eq!(underline.thickness, 102.0 * v_scale);
+    let strikeout = scaled.strikeout_metrics().unwrap();
+    assert_relative_eq!(strikeout.position, 512.0 * v_scale);
+    assert_relative_eq!(strikeout.thickness, 102.0 * v_scale);
+}
+
+#[test]
+fn underline_draw() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let scale = 40.0;
+    let glyphs = layout(&font, scale, point(10.3, 50.0), "ace");
+    let underline = Decoration::underline(&font, &glyphs).unwrap();
+
+    let scaled = font.as_scaled(scale);
+    let metrics = scaled.underline_metrics().unwrap();
+    let width: f32 = glyphs.iter().map(|g| scaled.h_advance(g.id)).sum();
+
+    let bounds = underline.px_bounds();
+    assert_eq!(bounds.min.x, 10.0);
+    assert_eq!(bounds.max.x, (10.3 + width).ceil());
+    assert_eq!(bounds.min.y, (50.0 - metrics.position).floor());
+    assert_eq!(
+        bounds.max.y,
+        (50.0 - metrics.position + metrics.thickness).ceil()
+    );
+
+    let (columns, total) = draw_columns(&underline);
+    assert_relative_eq!(total, width * metrics.thickness, epsilon = 1e-3);
+    assert_relative_eq!(columns[0], 0.7 * metrics.thickness, epsilon = 1e-3);
+    assert_relative_eq!(columns[1], metrics.thickness, epsilon = 1e-3);
+
+    // no glyphs cross the underline
+    let skip_ink = underline.clone().with_skip_ink(&font, &glyphs, 1.0);
+    assert_relative_eq!(draw_columns(&skip_ink).1, total);
+}
+
+#[test]
+fn underline_skip_ink() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let glyphs = layout(&font, 40.0, point(0.0, 50.0), "agpa");
+    let underline = Decoration::underline(&font, &glyphs).unwrap();
+    let skip_ink = underline.clone().with_skip_ink(&font, &glyphs, 1.0);
+    assert_eq!(skip_ink.px_bounds(), underline.px_bounds());
+
+    let (columns, total) = draw_columns(&underline);
+    let (skip_columns, skip_total) = draw_columns(&skip_ink);
+    assert!(skip_total < total * 0.9, "{skip_total} {total}");
+
+    // gaps only under descenders
+    let descender_x = |glyph: &Glyph| {
+        let bounds = font.outline_glyph(glyph.clone()).unwrap().px_bounds();
+        bounds.min.x - 1.0..bounds.max.x + 1.0
+    };
+    let (g, p) = (descender_x(&glyphs[1]), descender_x(&glyphs[2]));
+    for (x, (c, skip_c)) in columns.iter().zip(&skip_columns).enumerate() {
+        let x = x as f32 + underline.px_bounds().min.x;
+        if skip_c < c {
+            assert_eq!(*skip_c, 0.0);
+            assert!(g.contains(&x) || p.contains(&x), "unexpected gap at {x}");
+        }
+    }
+    let first_a = font.outline_glyph(glyphs[0].clone()).unwrap().px_bounds();
+    assert!(skip_columns[..first_a.max.x as usize - 2]
+        .iter()
+        .all(|c| *c > 0.0));
+}
+
+#[test]
+fn strikeout() {
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let glyphs = layout(&font, 24.0, point(0.0, 30.0), "strike");
+    let strikeout = Decoration::strikeout(&font, &glyphs).unwrap();
+    let bounds = strikeout.px_bounds();
+    assert!(bounds.max.y < 30.0);
+    assert!(bounds.min.y > 30.0 - font.as_scaled(24.0).ascent());
+
+    // glyphs cross a strikeout, so skip ink leaves gaps
+    let skip_ink = strikeout.clone().with_skip_ink(&font, &glyphs, 0.0);
+    assert!(draw_columns(&skip_ink).1 < draw_columns(&strikeout).1);
+}
+
+#[test]
+fn empty_decoration() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    assert!(Decoration::underline(&font, &[]).is_none());
+
+    let glyphs = layout(&font, 24.0, point(0.0, 30.0), "a");
+    let metrics = DecorationMetrics {
+        position: -75.0,
+        thickness: 0.0,
+    };
+    assert!(Decoration::new(&font, &glyphs, metrics).is_none());
+}
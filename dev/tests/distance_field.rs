@@ -0,0 +1,376 @@
+use ab_glyph::*;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_TTF: &[u8] = include_bytes!("../fonts/Exo2-Light.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns an outlined glyph of `curves` at 1:1 pixel scale, with y flipped
+/// as usual for font outlines. Note: outline `bounds` are `min: (x_min, y_max)`,
+/// `max: (x_max, y_min)`.
+fn outlined(curves: Vec<OutlineCurve>, bounds: Rect) -> OutlinedGlyph {
+    let glyph = GlyphId(0).with_scale(1.0);
+    OutlinedGlyph::new(
+        glyph,
+        Outline::new(bounds, curves, vec![0]),
+        PxScaleFactor {
+            horizontal: 1.0,
+            vertical: 1.0,
+        },
+    )
+}
+
+/// Returns `(x, y, distance)` for each sdf pixel.
+fn sdf_pixels(glyph: &OutlinedGlyph, spread: f32) -> Vec<(u32, u32, f32)> {
+    let mut pixels = vec![];
+    glyph.draw_sdf(spread, |x, y, d| pixels.push((x, y, d)));
+    pixels
+}
+
+#[test]
+fn sdf_square() {
+    let square = outlined(
+        vec![
+            OutlineCurve::Line(point(0.0, 0.0), point(0.0, 10.0)),
+            OutlineCurve::Line(point(0.0, 10.0), point(10.0, 10.0)),
+            OutlineCurve::Line(point(10.0, 10.0), point(10.0, 0.0)),
+            OutlineCurve::Line(point(10.0, 0.0), point(0.0, 0.0)),
+        ],
+        Rect {
+            min: point(0.0, 10.0),
+            max: point(10.0, 0.0),
+        },
+    );
+    let spread = 3.0;
+    let bounds = square.sdf_px_bounds(spread);
+    assert_eq!(bounds.width(), 16.0);
+    assert_eq!(bounds.height(), 16.0);
+
+    let pixels = sdf_pixels(&square, spread);
+    assert_eq!(pixels.len(), 16 * 16);
+    for (x, y, d) in pixels {
+        // square spans 3..13 in sdf pixel space
+        let (cx, cy) = (x as f32 + 0.5 - 8.0, y as f32 + 0.5 - 8.0);
+        let (qx, qy) = (cx.abs() - 5.0, cy.abs() - 5.0);
+        let outside = (qx.max(0.0).powi(2) + qy.max(0.0).powi(2)).sqrt();
+        let expected = -(outside + qx.max(qy).min(0.0));
+        let expected = expected.clamp(-spread, spread);
+        assert!(
+            (d - expected).abs() < 1e-5,
+            "({x}, {y}) distance {d} != {expected}"
+        );
+    }
+}
+
+/// Quadratic & cubic distances should match a brute force search of curve points.
+#[test]
+fn sdf_curves_eq_brute_force() {
+    let quad = OutlineCurve::Quad(point(0.0, 0.0), point(6.0, 20.0), point(14.0, 0.0));
+    let cubic = OutlineCurve::Cubic(
+        point(0.0, 0.0),
+        point(-4.0, 16.0),
+        point(20.0, 12.0),
+        point(14.0, 0.0),
+    );
+    for curve in [quad, cubic] {
+        let glyph = outlined(
+            vec![curve, OutlineCurve::Line(point(14.0, 0.0), point(0.0, 0.0))],
+            Rect {
+                min: point(-2.0, 10.0),
+                max: point(16.0, 0.0),
+            },
+        );
+        let spread = 4.0;
+
+        // sample outline points in sdf pixel space
+        let min = glyph.px_bounds().min - glyph.sdf_px_bounds(spread).min;
+        let to_px = |p: Point| point(p.x - -2.0 + min.x, -p.y - -10.0 + min.y);
+        let samples: Vec<Point> = (0..=20_000)
+            .map(|i| i as f32 / 20_000.0)
+            .flat_map(|t| {
+                let mt = 1.0 - t;
+                let curve_point = match curve {
+                    OutlineCurve::Quad(p0, p1, p2) => point(
+                        mt * mt * p0.x + 2.0 * mt * t * p1.x + t * t * p2.x,
+                        mt * mt * p0.y + 2.0 * mt * t * p1.y + t * t * p2.y,
+                    ),
+                    OutlineCurve::Cubic(p0, p1, p2, p3) => point(
+                        mt * mt * mt * p0.x
+                            + 3.0 * mt * mt * t * p1.x
+                            + 3.0 * mt * t * t * p2.x
+                            + t * t * t * p3.x,
+                        mt * mt * mt * p0.y
+                            + 3.0 * mt * mt * t * p1.y
+                            + 3.0 * mt * t * t * p2.y
+                            + t * t * t * p3.y,
+                    ),
+                    _ => unreachable!(),
+                };
+                [to_px(curve_point), to_px(point(14.0 * t, 0.0))]
+            })
+            .collect();
+
+        for (x, y, d) in sdf_pixels(&glyph, spread) {
+            let center = point(x as f32 + 0.5, y as f32 + 0.5);
+            let brute = samples
+                .iter()
+                .map(|s| ((s.x - center.x).powi(2) + (s.y - center.y).powi(2)).sqrt())
+                .fold(f32::MAX, f32::min)
+                .min(spread);
+            assert!(
+                (d.abs() - brute).abs() < 2e-3,
+                "{curve:?} ({x}, {y}) distance {d} != {brute}"
+            );
+        }
+    }
+}
+
+/// Distance signs should agree with drawn coverage.
+#[test]
+fn sdf_sign_matches_coverage() {
+    for (font_data, c, scale) in [
+        (DEJA_VU_MONO, 'w', 30.0),
+        (EXO2_TTF, 'ę', 60.0),
+        (EXO2_OTF, 'ę', 60.0),
+    ] {
+        let font = FontRef::try_from_slice(font_data)
+            .unwrap()
+            .into_scaled(scale);
+        let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+        let bounds = glyph.px_bounds();
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        let mut coverage = vec![0.0; w * h];
+        glyph.draw_into(&mut coverage, 0, w);
+
+        let spread = 2.0;
+        let pad = (bounds.min - glyph.sdf_px_bounds(spread).min).x as u32;
+        glyph.draw_sdf(spread, |x, y, d| {
+            let (Some(x), Some(y)) = (x.checked_sub(pad), y.checked_sub(pad)) else {
+                return assert!(d < 0.0, "outside px_bounds distance {d}");
+            };
+            let (x, y) = (x as usize, y as usize);
+            if x >= w || y >= h {
+                return assert!(d < 0.0, "outside px_bounds distance {d}");
+            }
+            let c = coverage[y * w + x];
+            if c > 0.999 {
+                assert!(d > 0.0, "{c:?} ({x}, {y}) covered distance {d}");
+            } else if c < 0.001 {
+                assert!(d < 0.5, "{c:?} ({x}, {y}) uncovered distance {d}");
+            } else if (0.01..0.99).contains(&c) {
+                // partially covered pixels contain the outline
+                assert!(d.abs() < 0.71, "{c:?} ({x}, {y}) edge distance {d}");
+            }
+        });
+    }
+}
+
+#[test]
+fn sdf_into_u8() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO)
+        .unwrap()
+        .into_scaled(30.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('l')).unwrap();
+    let spread = 4.0;
+    let bounds = glyph.sdf_px_bounds(spread);
+    let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+
+    let mut sdf = vec![7u8; (w + 3) * h];
+    glyph.draw_sdf_into(spread, &mut sdf, 3, w + 3);
+
+    let mut distances = vec![0.0; w * h];
+    glyph.draw_sdf(spread, |x, y, d| distances[y as usize * w + x as usize] = d);
+
+    for y in 0..h {
+        assert_eq!(sdf[y * (w + 3)..][..3], [7; 3]);
+        for x in 0..w {
+            let expected = 0.5 + distances[y * w + x] / (2.0 * spread);
+            let expected = (expected * 255.0 + 0.5) as u8;
+            assert_eq!(sdf[y * (w + 3) + 3 + x], expected);
+        }
+    }
+    // corners are outside & far from the outline
+    assert_eq!(sdf[3], 0);
+    assert!(sdf.iter().any(|v| *v > 128));
+}
+
+/// A zero, negative or NaN spread should draw a hard edge.
+#[test]
+fn sdf_into_hard_edge() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO)
+        .unwrap()
+        .into_scaled(30.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('l')).unwrap();
+    let bounds = glyph.px_bounds();
+    let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+
+    let spread = 4.0;
+    let sdf_w = glyph.sdf_px_bounds(spread).width() as usize;
+    let mut distances = vec![0.0; sdf_w * (h + 8)];
+    glyph.draw_sdf(spread, |x, y, d| {
+        distances[y as usize * sdf_w + x as usize] = d
+    });
+
+    for spread in [0.0, -2.0, f32::NAN] {
+        assert_eq!(glyph.sdf_px_bounds(spread), bounds);
+        let mut sdf = vec![7u8; w * h];
+        glyph.draw_sdf_into(spread, &mut sdf, 0, w);
+
+        for y in 0..h {
+            for x in 0..w {
+                let inside = distances[(y + 4) * sdf_w + x + 4] > 0.0;
+                let expected = if inside { 255 } else { 0 };
+                assert_eq!(sdf[y * w + x], expected, "spread {spread} ({x}, {y})");
+            }
+        }
+        assert!(sdf.contains(&255) && sdf.contains(&0));
+
+        let mut msdf = vec![[7u8; 4]; w * h];
+        glyph.draw_msdf_into(spread, &mut msdf, 0, w);
+        let alpha: Vec<_> = msdf.iter().map(|[.., a]| *a).collect();
+        assert_eq!(alpha, sdf, "spread {spread}");
+    }
+}
+
+#[inline]
+fn median([r, g, b, _]: [f32; 4]) -> f32 {
+    r.min(g).max(r.max(g).min(b))
+}
+
+/// The median of msdf channels should keep square corners sharp, i.e. equal the
+/// distance to the nearest edge line rather than rounded corner distance.
+#[test]
+fn msdf_square_sharp_corners() {
+    let square = outlined(
+        vec![
+            OutlineCurve::Line(point(0.0, 0.0), point(0.0, 10.0)),
+            OutlineCurve::Line(point(0.0, 10.0), point(10.0, 10.0)),
+            OutlineCurve::Line(point(10.0, 10.0), point(10.0, 0.0)),
+            OutlineCurve::Line(point(10.0, 0.0), point(0.0, 0.0)),
+        ],
+        Rect {
+            min: point(0.0, 10.0),
+            max: point(10.0, 0.0),
+        },
+    );
+    let spread = 3.0;
+    let mut count = 0;
+    square.draw_msdf(spread, |x, y, distances| {
+        count += 1;
+        let (cx, cy) = (x as f32 + 0.5 - 8.0, y as f32 + 0.5 - 8.0);
+        let (qx, qy) = (cx.abs() - 5.0, cy.abs() - 5.0);
+        let expected = (-qx.max(qy)).clamp(-spread, spread);
+        let median = median(distances);
+        assert!(
+            (median - expected).abs() < 1e-4,
+            "({x}, {y}) median {median} != {expected}, {distances:?}"
+        );
+    });
+    assert_eq!(count, 16 * 16);
+}
+
+/// The msdf alpha channel is the true signed distance.
+#[test]
+fn msdf_alpha_eq_sdf() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap().into_scaled(40.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('M')).unwrap();
+    let spread = 3.0;
+    let sdf = sdf_pixels(&glyph, spread);
+    let mut msdf = vec![];
+    glyph.draw_msdf(spread, |x, y, [.., a]| msdf.push((x, y, a)));
+    assert_eq!(msdf, sdf);
+}
+
+/// Smooth outlines have no corners so the median should match the sdf.
+#[test]
+fn msdf_smooth_eq_sdf() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO)
+        .unwrap()
+        .into_scaled(40.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('o')).unwrap();
+    let spread = 3.0;
+    glyph.draw_msdf(spread, |x, y, distances| {
+        let median = median(distances);
+        let sdf = distances[3];
+        assert!(
+            (median - sdf).abs() < 0.05,
+            "({x}, {y}) median {median} != {sdf}"
+        );
+    });
+}
+
+/// Median signs should agree with drawn coverage.
+#[test]
+fn msdf_sign_matches_coverage() {
+    for (font_data, c, scale) in [
+        (DEJA_VU_MONO, 'W', 30.0),
+        (DEJA_VU_MONO, 'M', 30.0),
+        (EXO2_TTF, 'ę', 60.0),
+        (EXO2_OTF, 'W', 60.0),
+        (EXO2_OTF, '&', 60.0),
+        // touching contours
+        (DEJA_VU_MONO, '≑', 30.0),
+    ] {
+        let font = FontRef::try_from_slice(font_data)
+            .unwrap()
+            .into_scaled(scale);
+        let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+        let bounds = glyph.px_bounds();
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        let mut coverage = vec![0.0; w * h];
+        glyph.draw_into(&mut coverage, 0, w);
+
+        let spread = 2.0;
+        let pad = (bounds.min - glyph.sdf_px_bounds(spread).min).x as u32;
+        glyph.draw_msdf(spread, |x, y, distances| {
+            let d = median(distances);
+            let (Some(x), Some(y)) = (x.checked_sub(pad), y.checked_sub(pad)) else {
+                return assert!(d < 0.0, "{c:?} outside px_bounds distance {d}");
+            };
+            let (x, y) = (x as usize, y as usize);
+            if x >= w || y >= h {
+                return assert!(d < 0.0, "{c:?} outside px_bounds distance {d}");
+            }
+            let cov = coverage[y * w + x];
+            if cov > 0.999 {
+                assert!(d > 0.0, "{c:?} {cov:?} ({x}, {y}) covered distance {d}");
+            } else if cov < 0.001 {
+                assert!(d < 0.5, "{c:?} {cov:?} ({x}, {y}) uncovered distance {d}");
+            }
+        });
+    }
+}
+
+#[test]
+fn msdf_into_u8() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap().into_scaled(30.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('W')).unwrap();
+    let spread = 4.0;
+    let bounds = glyph.sdf_px_bounds(spread);
+    let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+
+    let mut rgb = vec![[7u8; 3]; (w + 3) * h];
+    glyph.draw_msdf_into(spread, &mut rgb, 3, w + 3);
+    let mut rgba = vec![[0u8; 4]; w * h];
+    glyph.draw_msdf_into(spread, &mut rgba, 0, w);
+
+    let mut distances = vec![[0.0; 4]; w * h];
+    glyph.draw_msdf(spread, |x, y, d| distances[y as usize * w + x as usize] = d);
+
+    let to_u8 = |d: f32| ((0.5 + d / (2.0 * spread)) * 255.0 + 0.5) as u8;
+    for y in 0..h {
+        assert_eq!(rgb[y * (w + 3)..][..3], [[7; 3]; 3]);
+        for x in 0..w {
+            let expected = distances[y * w + x].map(to_u8);
+            assert_eq!(rgb[y * (w + 3) + 3 + x], expected[..3]);
+            assert_eq!(rgba[y * w + x], expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "2 channels")]
+fn msdf_into_2_channels() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap().into_scaled(30.0);
+    let glyph = font.outline_glyph(font.scaled_glyph('W')).unwrap();
+    glyph.draw_msdf_into(4.0, &mut [[0u8; 2]; 64 * 64], 0, 64);
+}
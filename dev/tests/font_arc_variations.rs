@@ -0,0 +1,141 @@
+use ab_glyph::*;
+use std::sync::Arc;
+
+const CANTARELL_VF: &[u8] = include_bytes!("../fonts/Cantarell-VF.otf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+#[test]
+fn set_variation_shares_data() {
+    let data: Arc<[u8]> = CANTARELL_VF.into();
+    let regular = FontArc::try_from_data(Arc::clone(&data)).unwrap();
+    let o = regular.glyph_id('o');
+    let regular_advance = regular.h_advance_unscaled(o);
+
+    let mut bold = regular.clone();
+    assert!(bold.set_variation(b"wght", 700.0));
+    assert_eq!(bold.font_data().as_ptr(), data.as_ptr());
+    assert_eq!(Arc::strong_count(&data), 2);
+
+    // clones are unaffected
+    assert_eq!(regular.h_advance_unscaled(o), regular_advance);
+    assert!(bold.h_advance_unscaled(o) > regular_advance);
+
+    let mut font_ref = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    font_ref.set_variation(b"wght", 700.0);
+    assert_eq!(bold.h_advance_unscaled(o), font_ref.h_advance_unscaled(o));
+    assert_eq!(
+        bold.outline(o).unwrap().bounds,
+        font_ref.outline(o).unwrap().bounds
+    );
+
+    // variations accumulate
+    let mut bolder = bold.clone();
+    assert!(bolder.set_variation(b"wght", 800.0));
+    assert!(bolder.h_advance_unscaled(o) > bold.h_advance_unscaled(o));
+}
+
+#[test]
+fn arc_variable_font_info() {
+    let mut font = FontArc::try_from_vec(CANTARELL_VF.to_vec()).unwrap();
+    let font_ref = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+
+    let axes = font.variations();
+    assert_eq!(axes.len(), 1);
+    assert_eq!(axes[0].tag, *b"wght");
+    assert_eq!(axes[0].name.as_deref(), Some("Weight"));
+    assert_eq!(font.named_instances(), font_ref.named_instances());
+
+    let o = font.glyph_id('o');
+    let regular_advance = font.h_advance_unscaled(o);
+    assert!(!font.set_variation(b"foob", 200.0));
+    assert!(!font.set_named_instance(5));
+    assert_eq!(font.h_advance_unscaled(o), regular_advance);
+
+    assert!(font.set_named_instance(0));
+    assert!(font.h_advance_unscaled(o) < regular_advance);
+}
+
+#[test]
+fn unvaried_font_arcs() {
+    // not a variable font
+    let mut font = FontArc::try_from_slice(EXO2_OTF).unwrap();
+    assert!(font.variations().is_empty());
+    assert!(!font.set_variation(b"wght", 700.0));
+
+    // fonts wrapped with `FontArc::new` cannot be varied
+    let font_ref = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    let font_vec = FontVec::try_from_vec(CANTARELL_VF.to_vec()).unwrap();
+    for mut font in [
+        FontArc::new(SyntheticFont::new(font_ref.clone())),
+        FontArc::new(font_ref),
+        FontArc::new(font_vec),
+    ] {
+        assert!(font.variations().is_empty());
+        assert!(font.named_instances().is_empty());
+        assert!(!font.set_variation(b"wght", 700.0));
+    }
+}
+
+/// Fonts converted into a `FontArc` should be variable, keeping their variations.
+#[test]
+fn converted_font_arcs() {
+    let mut font_vec = FontVec::try_from_vec(CANTARELL_VF.to_vec()).unwrap();
+    assert!(font_vec.set_variation(b"wght", 700.0));
+    let mut font_ref = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    assert!(font_ref.set_variation(b"wght", 700.0));
+    let o = font_ref.glyph_id('o');
+    let bold_advance = font_ref.h_advance_unscaled(o);
+
+    let data_ptr = font_vec.as_slice().as_ptr();
+    let fonts = [
+        FontArc::from(font_ref),
+        FontArc::from(font_vec),
+        FontArc::from(FontVec::try_from_vec(CANTARELL_VF.to_vec()).unwrap()),
+    ];
+    assert_eq!(fonts[1].font_data().as_ptr(), data_ptr);
+    for (idx, mut font) in fonts.into_iter().enumerate() {
+        assert_eq!(font.variations().len(), 1, "{idx}");
+        if idx < 2 {
+            assert_eq!(font.h_advance_unscaled(o), bold_advance, "{idx}");
+        }
+        assert!(font.set_variation(b"wght", 100.0), "{idx}");
+        assert!(font.h_advance_unscaled(o) < bold_advance, "{idx}");
+    }
+}
+
+/// Instances derived from one font can be used concurrently.
+#[test]
+fn concurrent_instances() {
+    let font = FontArc::try_from_slice(CANTARELL_VF).unwrap();
+    let weights = [100.0, 400.0, 700.0, 800.0];
+
+    let advances: Vec<f32> = std::thread::scope(|scope| {
+        let threads: Vec<_> = weights
+            .into_iter()
+            .map(|weight| {
+                let mut font = font.clone();
+                scope.spawn(move || {
+                    assert!(font.set_variation(b"wght", weight));
+                    let glyph = font.glyph_id('W').with_scale(40.0);
+                    let outlined = font.outline_glyph(glyph).unwrap();
+                    let mut area = 0.0;
+                    outlined.draw(|_, _, c| area += c);
+                    area
+                })
+            })
+            .collect();
+        threads.into_iter().map(|t| t.join().unwrap()).collect()
+    });
+
+    for pair in advances.windows(2) {
+        assert!(pair[0] < pair[1], "{advances:?}");
+    }
+}
+
+#[test]
+fn collection_face_variations() {
+    let collection = FontCollection::new(CANTARELL_VF);
+    let mut font = collection.face(0).unwrap();
+    assert!(font.set_named_instance(3));
+    assert_eq!(font.font_data().as_ptr(), collection.data().as_ptr());
+}
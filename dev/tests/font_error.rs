@@ -0,0 +1,89 @@
+use ab_glyph::*;
+
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns a font collection header of `face_count` faces all at `offset`.
+fn collection_header(face_count: u32, offset: u32) -> Vec<u8> {
+    let mut data = b"ttcf".to_vec();
+    data.extend(0x0001_0000_u32.to_be_bytes());
+    data.extend(face_count.to_be_bytes());
+    for _ in 0..face_count {
+        data.extend(offset.to_be_bytes());
+    }
+    data
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(
+        FontRef::parse(&EXO2_OTF[..8], 0).unwrap_err(),
+        FontError::Malformed
+    );
+    assert_eq!(
+        FontVec::parse(EXO2_OTF[..100].to_vec(), 0).unwrap_err(),
+        FontError::Malformed
+    );
+    assert_eq!(FontError::Malformed.to_string(), "Malformed font data");
+}
+
+#[test]
+fn unknown_magic() {
+    let err = FontRef::parse(b"wOF2 and some more data", 0).unwrap_err();
+    assert_eq!(err, FontError::UnknownMagic);
+    assert_eq!(err.to_string(), "Unknown font format magic number");
+}
+
+#[test]
+fn face_index_out_of_bounds() {
+    let err = FontRef::parse(EXO2_OTF, 1).unwrap_err();
+    assert_eq!(
+        err,
+        FontError::FaceIndexOutOfBounds {
+            index: 1,
+            face_count: 1
+        }
+    );
+    assert_eq!(err.to_string(), "Face index 1 out of bounds of 1 faces");
+
+    let collection = collection_header(2, 20);
+    assert_eq!(
+        FontVec::parse(collection, 5).unwrap_err(),
+        FontError::FaceIndexOutOfBounds {
+            index: 5,
+            face_count: 2
+        }
+    );
+}
+
+#[test]
+fn required_table() {
+    // sfnt header without tables
+    let mut data = 0x0001_0000_u32.to_be_bytes().to_vec();
+    data.extend([0; 8]);
+    let err = FontRef::parse(&data, 0).unwrap_err();
+    assert_eq!(err, FontError::RequiredTable { tag: *b"head" });
+    assert_eq!(
+        err.to_string(),
+        "Missing or malformed required table 'head'"
+    );
+}
+
+/// Existing constructors should return `InvalidFont` for any error.
+#[test]
+fn invalid_font_compat() {
+    assert_eq!(
+        FontRef::try_from_slice(b"nonsense").unwrap_err(),
+        InvalidFont
+    );
+    assert_eq!(
+        FontVec::try_from_vec_and_index(EXO2_OTF.to_vec(), 3).unwrap_err(),
+        InvalidFont
+    );
+    assert_eq!(InvalidFont::from(FontError::UnknownMagic), InvalidFont);
+
+    fn parse(data: &[u8]) -> Result<FontRef<'_>, InvalidFont> {
+        Ok(FontRef::parse(data, 0)?)
+    }
+    assert!(parse(EXO2_OTF).is_ok());
+    assert_eq!(parse(&EXO2_OTF[..4]).unwrap_err(), InvalidFont);
+}
@@ -0,0 +1,148 @@
+use ab_glyph::*;
+use approx::assert_relative_eq;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const AIRSTRIP: &[u8] = include_bytes!("../fonts/airstrip.ttf");
+const CANTARELL_VF: &[u8] = include_bytes!("../fonts/Cantarell-VF.otf");
+
+/// Returns `font` with OS/2 fsSelection bits `flags` set.
+fn with_fs_selection(font: &[u8], flags: u16) -> Vec<u8> {
+    let num_tables = u16::from_be_bytes([font[4], font[5]]) as usize;
+    let record = (0..num_tables)
+        .map(|idx| 12 + 16 * idx)
+        .find(|&record| &font[record..record + 4] == b"OS/2")
+        .unwrap();
+    let offset = u32::from_be_bytes(font[record + 8..record + 12].try_into().unwrap()) as usize;
+    let mut data = font.to_vec();
+    let fs_selection = u16::from_be_bytes([data[offset + 62], data[offset + 63]]) | flags;
+    data[offset + 62..offset + 64].copy_from_slice(&fs_selection.to_be_bytes());
+    data
+}
+
+#[test]
+fn os2_metrics() {
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    assert_eq!(
+        font.os2_metrics(),
+        Some(Os2Metrics {
+            typo_ascent: 1567.0,
+            typo_descent: -492.0,
+            typo_line_gap: 132.0,
+            win_ascent: 2189.0,
+            win_descent: -600.0,
+            x_height: Some(1096.0),
+            cap_height: Some(1462.0),
+            weight_class: 400,
+            width_class: 5,
+            fs_selection: FsSelection(FsSelection::ITALIC),
+        })
+    );
+    let fs_selection = font.os2_metrics().unwrap().fs_selection;
+    assert!(fs_selection.is_italic());
+    assert!(!fs_selection.is_bold());
+    assert!(!fs_selection.use_typo_metrics());
+
+    // version 1 OS/2 table
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let os2 = font.os2_metrics().unwrap();
+    assert_eq!((os2.x_height, os2.cap_height), (None, None));
+    assert!(os2.fs_selection.is_regular());
+
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let os2 = font.os2_metrics().unwrap();
+    assert_eq!(os2.weight_class, 300);
+    assert!(os2.fs_selection.use_typo_metrics());
+}
+
+#[test]
+fn line_metrics_modes() {
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let hhea = LineMetrics {
+        ascent: 2189.0,
+        descent: -600.0,
+        line_gap: 0.0,
+    };
+    assert_eq!(font.line_metrics_unscaled(LineMetricsMode::Hhea), hhea);
+    assert_eq!(font.line_metrics_unscaled(LineMetricsMode::Auto), hhea);
+    assert_eq!(font.line_metrics_unscaled(LineMetricsMode::default()), hhea);
+    assert_eq!(
+        font.line_metrics_unscaled(LineMetricsMode::Typo),
+        LineMetrics {
+            ascent: 1567.0,
+            descent: -492.0,
+            line_gap: 132.0,
+        }
+    );
+    assert_eq!(font.line_metrics_unscaled(LineMetricsMode::Win), hhea);
+
+    let auto = font.line_metrics_unscaled(LineMetricsMode::Auto);
+    assert_eq!(auto.ascent, font.ascent_unscaled());
+    assert_eq!(auto.descent, font.descent_unscaled());
+    assert_eq!(auto.line_gap, font.line_gap_unscaled());
+    assert_eq!(auto.height(), font.height_unscaled());
+}
+
+/// Win line gap should be the hhea line gap in excess of the win height.
+#[test]
+fn win_line_gap() {
+    let font = FontRef::try_from_slice(AIRSTRIP).unwrap();
+    let hhea = font.line_metrics_unscaled(LineMetricsMode::Hhea);
+    assert_eq!(hhea.line_height(), 1854.0 + 434.0 + 67.0);
+
+    let win = font.line_metrics_unscaled(LineMetricsMode::Win);
+    assert_eq!((win.ascent, win.descent), (1854.0, -434.0));
+    assert_eq!(win.line_gap, 67.0);
+    assert_eq!(win.line_height(), hhea.line_height());
+}
+
+#[test]
+fn use_typo_metrics() {
+    let font = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    assert_eq!(font.ascent_unscaled(), 983.0);
+
+    let font = FontVec::try_from_vec(with_fs_selection(
+        CANTARELL_VF,
+        FsSelection::USE_TYPO_METRICS,
+    ))
+    .unwrap();
+    assert!(font.os2_metrics().unwrap().fs_selection.use_typo_metrics());
+
+    let typo = font.line_metrics_unscaled(LineMetricsMode::Typo);
+    assert_eq!(typo.ascent, 739.0);
+    assert_eq!(font.line_metrics_unscaled(LineMetricsMode::Auto), typo);
+    assert_eq!(font.ascent_unscaled(), typo.ascent);
+    assert_eq!(
+        font.line_metrics_unscaled(LineMetricsMode::Hhea),
+        LineMetrics {
+            ascent: 983.0,
+            descent: -217.0,
+            line_gap: 0.0,
+        }
+    );
+}
+
+#[test]
+fn scaled_metrics() {
+    let font = FontArc::try_from_slice(EXO2_OTF).unwrap();
+    let synthetic = SyntheticFont::new(&font).with_oblique_angle(-12.0);
+    let scaled = synthetic.as_scaled(36.0);
+    let v_scale = scaled.v_scale_factor();
+
+    let lines = scaled.line_metrics(LineMetricsMode::Win);
+    assert_relative_eq!(lines.ascent, 1158.0 * v_scale);
+    assert_relative_eq!(lines.descent, -311.0 * v_scale);
+    assert_eq!(lines.line_gap, 0.0);
+
+    let auto = scaled.line_metrics(LineMetricsMode::Auto);
+    assert_relative_eq!(auto.ascent, scaled.ascent());
+    assert_relative_eq!(auto.descent, scaled.descent());
+    assert_relative_eq!(auto.height(), scaled.height());
+
+    assert_relative_eq!(scaled.x_height().unwrap(), 485.0 * v_scale);
+    assert_relative_eq!(scaled.cap_height().unwrap(), 690.0 * v_scale);
+
+    let deja_vu = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    assert_eq!(deja_vu.as_scaled(36.0).x_height(), None);
+}
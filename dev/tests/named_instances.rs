@@ -0,0 +1,68 @@
+use ab_glyph::*;
+
+const CANTARELL_VF: &[u8] = include_bytes!("../fonts/Cantarell-VF.otf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+#[test]
+fn named_instances() {
+    let font = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    let instances = font.named_instances();
+
+    let names: Vec<_> = instances.iter().map(|i| i.name.as_deref()).collect();
+    assert_eq!(
+        names,
+        [
+            Some("Thin"),
+            Some("Light"),
+            Some("Regular"),
+            Some("Bold"),
+            Some("Extra Bold")
+        ]
+    );
+    let weights: Vec<_> = instances.iter().map(|i| i.coordinates.clone()).collect();
+    assert_eq!(
+        weights,
+        [100.0, 300.0, 400.0, 700.0, 800.0].map(|w| vec![(*b"wght", w)])
+    );
+    assert!(instances.iter().all(|i| i.postscript_name.is_none()));
+
+    // FontVec reads the same instances
+    let font_vec = FontVec::try_from_vec(CANTARELL_VF.to_vec()).unwrap();
+    assert_eq!(font_vec.named_instances(), instances);
+}
+
+#[test]
+fn set_named_instance() {
+    let regular = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    let mut bold = regular.clone();
+    let mut manual_bold = regular.clone();
+    assert!(bold.set_named_instance(3));
+    assert!(manual_bold.set_variation(b"wght", 700.0));
+
+    let o = regular.glyph_id('o');
+    let bold_advance = bold.h_advance_unscaled(o);
+    assert!(bold_advance > regular.h_advance_unscaled(o));
+    assert_eq!(bold_advance, manual_bold.h_advance_unscaled(o));
+    assert_eq!(
+        bold.outline(o).unwrap().bounds,
+        manual_bold.outline(o).unwrap().bounds
+    );
+
+    let mut font_vec = FontVec::try_from_vec(CANTARELL_VF.to_vec()).unwrap();
+    assert!(font_vec.set_named_instance(3));
+    assert_eq!(font_vec.h_advance_unscaled(o), bold_advance);
+}
+
+#[test]
+fn missing_named_instance() {
+    let mut font = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    let o = font.glyph_id('o');
+    let advance = font.h_advance_unscaled(o);
+    assert!(!font.set_named_instance(5));
+    assert_eq!(font.h_advance_unscaled(o), advance);
+
+    // not a variable font
+    let mut font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    assert!(font.named_instances().is_empty());
+    assert!(!font.set_named_instance(0));
+}
@@ -0,0 +1,212 @@
+use ab_glyph::*;
+use dev::{read_u16, read_u32};
+
+const EXO2_TTF: &[u8] = include_bytes!("../fonts/Exo2-Light.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
+
+const JAPANESE: u16 = 0x0411;
+const ENGLISH_UK: u16 = 0x0809;
+const ENGLISH_AU: u16 = 0x0c09;
+const GERMAN: u16 = 0x0407;
+
+/// Returns `font` with a new name table of Windows unicode `(name_id, language_id, value)` names.
+fn with_names(font: &[u8], names: &[(u16, u16, &str)]) -> Vec<u8> {
+    let mut storage = vec![];
+    let mut table = vec![];
+    table.extend(0_u16.to_be_bytes());
+    table.extend((names.len() as u16).to_be_bytes());
+    table.extend((6 + 12 * names.len() as u16).to_be_bytes());
+    for &(name_id, language_id, value) in names {
+        let utf16: Vec<u8> = value.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        for field in [3, 1, language_id, name_id, utf16.len() as u16] {
+            table.extend(field.to_be_bytes());
+        }
+        table.extend((storage.len() as u16).to_be_bytes());
+        storage.extend(utf16);
+    }
+    table.extend(storage);
+
+    let mut data = font.to_vec();
+    data.resize(data.len().next_multiple_of(4), 0);
+    let table_offset = data.len() as u32;
+    data.extend(&table);
+
+    let num_tables = read_u16(font, 4) as usize;
+    let record = (0..num_tables)
+        .map(|idx| 12 + 16 * idx)
+        .find(|&record| &font[record..record + 4] == b"name")
+        .unwrap();
+    assert!(read_u32(font, record + 8) > 0);
+    data[record + 8..record + 12].copy_from_slice(&table_offset.to_be_bytes());
+    data[record + 12..record + 16].copy_from_slice(&(table.len() as u32).to_be_bytes());
+    data
+}
+
+fn localized_font() -> FontVec {
+    FontVec::try_from_vec(with_names(
+        EXO2_TTF,
+        &[
+            (1, 0x0409, "Exo 2 Light"),
+            (1, JAPANESE, "エクソ 2 Light"),
+            (1, ENGLISH_UK, "Exo 2 Light (UK)"),
+            (4, JAPANESE, "エクソ 2"),
+            (6, 0x0409, "Exo2-Light"),
+            (16, 0x0409, "Exo 2"),
+        ],
+    ))
+    .unwrap()
+}
+
+#[test]
+fn standard_names() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    assert_eq!(font.name(NameId::FAMILY).as_deref(), Some("Exo 2 Light"));
+    assert_eq!(font.name(NameId::SUBFAMILY).as_deref(), Some("Regular"));
+    assert_eq!(font.name(NameId::FULL_NAME).as_deref(), Some("Exo 2 Light"));
+    assert_eq!(
+        font.name(NameId::POST_SCRIPT_NAME).as_deref(),
+        Some("Exo2-Light")
+    );
+    assert_eq!(font.name(NameId(9999)), None);
+
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    assert_eq!(
+        font.name(NameId::FULL_NAME).as_deref(),
+        Some("Open Sans Italic")
+    );
+}
+
+#[test]
+fn names_entries() {
+    let font = localized_font();
+    let names = font.names();
+    assert_eq!(names.len(), 6);
+    assert_eq!(
+        names[1],
+        FontName {
+            name_id: NameId::FAMILY,
+            language_id: Some(JAPANESE),
+            value: "エクソ 2 Light".into(),
+        }
+    );
+
+    // only unicode names are decoded
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let names = font.names();
+    assert!(!names.is_empty());
+    assert!(names.iter().all(|name| name.language_id == Some(0x0409)));
+}
+
+#[test]
+fn localized_name_selection() {
+    let font = localized_font();
+
+    let family = |language_id| font.localized_name(NameId::FAMILY, language_id);
+    assert_eq!(family(JAPANESE).as_deref(), Some("エクソ 2 Light"));
+    assert_eq!(family(ENGLISH_UK).as_deref(), Some("Exo 2 Light (UK)"));
+    assert_eq!(family(0x0409).as_deref(), Some("Exo 2 Light"));
+    // another english region falls back to the first english name
+    assert_eq!(family(ENGLISH_AU).as_deref(), Some("Exo 2 Light"));
+    // unavailable language falls back to US English
+    assert_eq!(family(GERMAN).as_deref(), Some("Exo 2 Light"));
+
+    // unavailable languages fall back to any language
+    assert_eq!(
+        font.localized_name(NameId::FULL_NAME, GERMAN).as_deref(),
+        Some("エクソ 2")
+    );
+    assert_eq!(font.name(NameId::FULL_NAME).as_deref(), Some("エクソ 2"));
+
+    assert_eq!(font.family_name().as_deref(), Some("Exo 2"));
+    assert_eq!(font.subfamily_name(), None);
+}
+
+/// Names should be available through wrapping fonts.
+#[test]
+fn names_font_arc() {
+    let font = FontArc::new(localized_font());
+    assert_eq!(font.names().len(), 6);
+    assert_eq!(
+        font.localized_name(NameId::FAMILY, JAPANESE).as_deref(),
+        Some("エクソ 2 Light")
+    );
+
+    let synthetic = SyntheticFont::new(&font).with_embolden(0.05);
+    assert_eq!(
+        synthetic
+            .localized_name(NameId::FAMILY, JAPANESE)
+            .as_deref(),
+        Some("エクソ 2 Light")
+    );
+    assert_eq!(
+        synthetic.name(NameId::POST_SCRIPT_NAME).as_deref(),
+        Some("Exo2-Light")
+    );
+}
+
+/// The default implementation selects from `Font::names`.
+#[test]
+fn default_localized_name() {
+    struct Names(FontVec);
+
+    impl Font for Names {
+        fn units_per_em(&self) -> Option<f32> {
+            self.0.units_per_em()
+        }
+        fn ascent_unscaled(&self) -> f32 {
+            self.0.ascent_unscaled()
+        }
+        fn descent_unscaled(&self) -> f32 {
+            self.0.descent_unscaled()
+        }
+        fn line_gap_unscaled(&self) -> f32 {
+            self.0.line_gap_unscaled()
+        }
+        fn italic_angle(&self) -> f32 {
+            self.0.italic_angle()
+        }
+        fn glyph_id(&self, c: char) -> GlyphId {
+            self.0.glyph_id(c)
+        }
+        fn h_advance_unscaled(&self, id: GlyphId) -> f32 {
+            self.0.h_advance_unscaled(id)
+        }
+        fn h_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
+            self.0.h_side_bearing_unscaled(id)
+        }
+        fn v_advance_unscaled(&self, id: GlyphId) -> f32 {
+            self.0.v_advance_unscaled(id)
+        }
+        fn v_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
+            self.0.v_side_bearing_unscaled(id)
+        }
+        fn kern_unscaled(&self, first: GlyphId, second: GlyphId) -> f32 {
+            self.0.kern_unscaled(first, second)
+        }
+        fn outline(&self, id: GlyphId) -> Option<Outline> {
+            self.0.outline(id)
+        }
+        fn glyph_count(&self) -> usize {
+            self.0.glyph_count()
+        }
+        fn codepoint_ids(&self) -> CodepointIdIter<'_> {
+            self.0.codepoint_ids()
+        }
+        fn glyph_raster_image2(&self, id: GlyphId, size: u16) -> Option<v2::GlyphImage<'_>> {
+            self.0.glyph_raster_image2(id, size)
+        }
+        fn names(&self) -> Vec<FontName> {
+            self.0.names()
+        }
+    }
+
+    let font = Names(localized_font());
+    for language_id in [JAPANESE, ENGLISH_UK, ENGLISH_AU, GERMAN] {
+        assert_eq!(
+            font.localized_name(NameId::FAMILY, language_id),
+            font.0.localized_name(NameId::FAMILY, language_id)
+        );
+    }
+    assert_eq!(font.family_name().as_deref(), Some("Exo 2"));
+}
@@ -0,0 +1,136 @@
+use ab_glyph::*;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const CANTARELL_VF: &[u8] = include_bytes!("../fonts/Cantarell-VF.otf");
+
+/// Records outline commands as curves & counts contours.
+#[derive(Debug, Default)]
+struct Recorder {
+    start: Option<Point>,
+    last: Point,
+    curves: Vec<OutlineCurve>,
+    closes: usize,
+}
+
+impl OutlineSink for Recorder {
+    fn move_to(&mut self, to: Point) {
+        assert!(self.start.is_none(), "move_to before close");
+        self.start = Some(to);
+        self.last = to;
+    }
+
+    fn line_to(&mut self, to: Point) {
+        self.curves.push(OutlineCurve::Line(self.last, to));
+        self.last = to;
+    }
+
+    fn quad_to(&mut self, control: Point, to: Point) {
+        self.curves.push(OutlineCurve::Quad(self.last, control, to));
+        self.last = to;
+    }
+
+    fn cubic_to(&mut self, control1: Point, control2: Point, to: Point) {
+        self.curves
+            .push(OutlineCurve::Cubic(self.last, control1, control2, to));
+        self.last = to;
+    }
+
+    fn close(&mut self) {
+        let start = self.start.take().expect("close without move_to");
+        self.curves.push(OutlineCurve::Line(self.last, start));
+        self.closes += 1;
+    }
+}
+
+/// Returns the points of each curve, ignoring zero length lines.
+fn curve_points(curves: &[OutlineCurve]) -> Vec<Vec<Point>> {
+    curves
+        .iter()
+        .filter_map(|curve| match *curve {
+            OutlineCurve::Line(p0, p1) if p0 == p1 => None,
+            OutlineCurve::Line(p0, p1) => Some(vec![p0, p1]),
+            OutlineCurve::Quad(p0, p1, p2) => Some(vec![p0, p1, p2]),
+            OutlineCurve::Cubic(p0, p1, p2, p3) => Some(vec![p0, p1, p2, p3]),
+        })
+        .collect()
+}
+
+/// `outline_into` should produce the same curves & bounds as `outline`.
+fn assert_outline_into_eq_outline(font: impl Font, c: char) {
+    let id = font.glyph_id(c);
+    let outline = font.outline(id).unwrap();
+
+    let mut recorder = Recorder::default();
+    let bounds = font.outline_into(id, &mut recorder);
+    assert_eq!(bounds, Some(outline.bounds), "{c:?}");
+    assert!(recorder.start.is_none(), "{c:?} contour not closed");
+    assert_eq!(recorder.closes, outline.contours().len(), "{c:?}");
+    assert_eq!(
+        curve_points(&recorder.curves),
+        curve_points(&outline.curves),
+        "{c:?}"
+    );
+}
+
+#[test]
+fn outline_into_font_ref() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    // '≑' has touching contours
+    for c in ['a', 'B', '%', '@', '≑'] {
+        assert_outline_into_eq_outline(&font, c);
+    }
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    for c in ['a', 'B', 'ę', '&'] {
+        assert_outline_into_eq_outline(&font, c);
+    }
+}
+
+/// Glyphs that implicitly close should still be closed.
+#[test]
+fn outline_into_implicitly_closed() {
+    let font = FontRef::try_from_slice(CANTARELL_VF).unwrap();
+    for c in ['a', 'o', 'x'] {
+        assert_outline_into_eq_outline(&font, c);
+    }
+}
+
+#[test]
+fn outline_into_font_vec_arc() {
+    let font = FontVec::try_from_vec(EXO2_OTF.to_vec()).unwrap();
+    assert_outline_into_eq_outline(&font, 'q');
+    assert_outline_into_eq_outline(FontArc::new(font), 'q');
+    assert_outline_into_eq_outline(FontArc::try_from_slice(DEJA_VU_MONO).unwrap(), 'q');
+}
+
+/// Fonts without their own `outline_into` should send the curves of `outline`.
+#[test]
+fn outline_into_default() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let bold = SyntheticFont::new(font).with_embolden(1.0 / 24.0);
+    assert_outline_into_eq_outline(&bold, 'B');
+    assert_outline_into_eq_outline(&bold, '≑');
+    assert_outline_into_eq_outline(FontArc::new(bold.with_oblique_angle(-12.0)), 'q');
+}
+
+/// Nothing should be sent for glyphs without an outline.
+#[test]
+fn outline_into_no_outline() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let mut recorder = Recorder::default();
+    assert_eq!(font.outline_into(font.glyph_id(' '), &mut recorder), None);
+    assert!(recorder.curves.is_empty());
+    assert_eq!(recorder.closes, 0);
+
+    for font_data in [DEJA_VU_MONO, EXO2_OTF, CANTARELL_VF] {
+        let font = FontRef::try_from_slice(font_data).unwrap();
+        for id in (0..font.glyph_count()).map(|id| GlyphId(id as _)) {
+            let mut recorder = Recorder::default();
+            if font.outline_into(id, &mut recorder).is_none() {
+                assert!(font.outline(id).is_none(), "{id:?}");
+                assert!(recorder.curves.is_empty(), "{id:?}");
+                assert_eq!(recorder.closes, 0, "{id:?}");
+            }
+        }
+    }
+}
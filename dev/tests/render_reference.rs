@@ -1,6 +1,10 @@
-use ab_glyph::{Font, FontRef, ScaleFont};
+use ab_glyph::{
+    FillRule, Font, FontRef, LcdFilter, LineJoin, OutlinedGlyph, ScaleFont, Stroke, SubpixelLayout,
+    Transform,
+};
 use ab_glyph_rasterizer::Rasterizer;
-use image::{DynamicImage, LumaA};
+use approx::assert_relative_eq;
+use image::{DynamicImage, LumaA, Rgb};
 use std::{env, io::Cursor, path::PathBuf};
 
 const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
@@ -61,6 +65,17 @@ fn reference_outline_draw_ttf_w() {
     compare_image!(new_image, include_bytes!("reference_ttf_w.png"));
 }
 
+/// Glyphs without overlapping contours should draw the same using either fill rule.
+#[test]
+fn reference_outline_draw_ttf_w_even_odd() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let new_image = outline_draw_with(font, 'w', 16.0, |g| g.with_fill_rule(FillRule::EvenOdd));
+    new_image
+        .save(temp_path("new_outlined_ttf_w_even_odd.png"))
+        .unwrap();
+    compare_image!(new_image, include_bytes!("reference_ttf_w.png"));
+}
+
 #[test]
 fn reference_draw_ttf_iota() {
     let new_image = draw_grey_image(dev::rasterize_ttf_iota());
@@ -129,6 +144,101 @@ fn reference_outline_draw_ttf_tailed_e() {
     compare_image!(new_image, include_bytes!("reference_ttf_tailed_e.png"));
 }
 
+#[test]
+fn reference_outline_draw_otf_tailed_e_fine_flattening() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.with_flattening_tolerance(0.02));
+    new_image
+        .save(temp_path("new_outlined_otf_tailed_e_fine.png"))
+        .unwrap();
+    compare_image!(new_image, include_bytes!("reference_otf_tailed_e_fine.png"));
+}
+
+#[test]
+fn reference_outline_draw_otf_tailed_e_coarse_flattening() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.with_flattening_tolerance(1.0));
+    new_image
+        .save(temp_path("new_outlined_otf_tailed_e_coarse.png"))
+        .unwrap();
+    compare_image!(
+        new_image,
+        include_bytes!("reference_otf_tailed_e_coarse.png")
+    );
+}
+
+#[test]
+fn reference_outline_draw_ttf_tailed_e_fine_flattening() {
+    let font = FontRef::try_from_slice(EXO2_TTF).unwrap();
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.with_flattening_tolerance(0.02));
+    new_image
+        .save(temp_path("new_outlined_ttf_tailed_e_fine.png"))
+        .unwrap();
+    compare_image!(new_image, include_bytes!("reference_ttf_tailed_e_fine.png"));
+}
+
+#[test]
+fn reference_outline_draw_ttf_tailed_e_coarse_flattening() {
+    let font = FontRef::try_from_slice(EXO2_TTF).unwrap();
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.with_flattening_tolerance(1.0));
+    new_image
+        .save(temp_path("new_outlined_ttf_tailed_e_coarse.png"))
+        .unwrap();
+    compare_image!(
+        new_image,
+        include_bytes!("reference_ttf_tailed_e_coarse.png")
+    );
+}
+
+/// Rotated by ~37° (exact 3-4-5 triangle coefficients) & skewed.
+#[test]
+fn reference_outline_draw_otf_tailed_e_transformed() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let rotate = Transform {
+        xx: 0.8,
+        yx: 0.6,
+        xy: -0.6,
+        yy: 0.8,
+        ..Transform::IDENTITY
+    };
+    let transform = rotate.then(Transform {
+        xy: -0.25,
+        ..Transform::IDENTITY
+    });
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.with_transform(transform));
+    new_image
+        .save(temp_path("new_outlined_otf_tailed_e_transformed.png"))
+        .unwrap();
+    compare_image!(
+        new_image,
+        include_bytes!("reference_otf_tailed_e_transformed.png")
+    );
+}
+
+#[test]
+fn reference_outline_draw_otf_tailed_e_stroked() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let stroke = Stroke::new(12.0).with_join(LineJoin::Round);
+    let new_image = outline_draw_with(font, 'ę', 300.0, |g| g.stroked(stroke));
+    new_image
+        .save(temp_path("new_outlined_otf_tailed_e_stroked.png"))
+        .unwrap();
+    compare_image!(
+        new_image,
+        include_bytes!("reference_otf_tailed_e_stroked.png")
+    );
+}
+
+#[test]
+fn reference_outline_draw_ttf_w_stroked() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let new_image = outline_draw_with(font, 'w', 120.0, |g| g.stroked(Stroke::new(6.0)));
+    new_image
+        .save(temp_path("new_outlined_ttf_w_stroked.png"))
+        .unwrap();
+    compare_image!(new_image, include_bytes!("reference_ttf_w_stroked.png"));
+}
+
 /// Cantarell f required an implicit outline "close" at the end.
 #[test]
 fn reference_outline_draw_cantarell_f() {
@@ -143,10 +253,188 @@ fn reference_outline_draw_cantarell_f() {
     );
 }
 
+/// `draw_into` an atlas should match reference images, allowing ±1 alpha rounding differences.
+#[test]
+fn reference_outline_draw_into_atlas() {
+    const ATLAS_WIDTH: usize = 400;
+    let cases: [(&[u8], char, f32, &[u8]); 4] = [
+        (
+            DEJA_VU_MONO,
+            'w',
+            16.0,
+            include_bytes!("reference_ttf_w.png"),
+        ),
+        (
+            OPENS_SANS_ITALIC,
+            'ΐ',
+            60.0,
+            include_bytes!("reference_ttf_iota.png"),
+        ),
+        (
+            EXO2_OTF,
+            'ę',
+            300.0,
+            include_bytes!("reference_otf_tailed_e.png"),
+        ),
+        (
+            CANTARELL_VF,
+            'f',
+            300.0,
+            include_bytes!("reference_outlined_cantarell_f.png"),
+        ),
+    ];
+
+    for (font_data, c, scale, reference_bytes) in cases {
+        let font = FontRef::try_from_slice(font_data)
+            .unwrap()
+            .into_scaled(scale);
+        let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+
+        let mut atlas = vec![0u8; ATLAS_WIDTH * 400];
+        let (x0, y0) = (13, 7);
+        glyph.draw_into(&mut atlas, y0 * ATLAS_WIDTH + x0, ATLAS_WIDTH);
+
+        let reference = image::load(Cursor::new(reference_bytes), image::ImageFormat::Png)
+            .expect("!image::load")
+            .to_luma_alpha8();
+        let bounds = glyph.px_bounds();
+        assert_eq!(
+            reference.dimensions(),
+            (bounds.width() as u32, bounds.height() as u32)
+        );
+
+        for (x, y, LumaA([_, alpha])) in reference.enumerate_pixels() {
+            let drawn = atlas[(y0 + y as usize) * ATLAS_WIDTH + x0 + x as usize];
+            assert!(
+                drawn.abs_diff(*alpha) <= 1,
+                "unexpected alpha difference drawing {c:?} at ({x}, {y}): {drawn} != {alpha}"
+            );
+        }
+        // outside glyph bounds is untouched
+        assert!(atlas[..y0 * ATLAS_WIDTH].iter().all(|a| *a == 0));
+    }
+}
+
+/// `draw_with` reusing a rasterizer, including shrinking it, should match reference images.
+#[test]
+fn reference_outline_draw_with_reused_rasterizer() {
+    let cases: [(&[u8], char, f32, &[u8]); 3] = [
+        (
+            CANTARELL_VF,
+            'f',
+            300.0,
+            include_bytes!("reference_outlined_cantarell_f.png"),
+        ),
+        (
+            DEJA_VU_MONO,
+            'w',
+            16.0,
+            include_bytes!("reference_ttf_w.png"),
+        ),
+        (
+            EXO2_OTF,
+            'ę',
+            300.0,
+            include_bytes!("reference_otf_tailed_e.png"),
+        ),
+    ];
+
+    let mut rasterizer = Rasterizer::new(0, 0);
+    for (font_data, c, scale, reference_bytes) in cases {
+        let font = FontRef::try_from_slice(font_data)
+            .unwrap()
+            .into_scaled(scale);
+        let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+        let bounds = glyph.px_bounds();
+
+        let mut new_image =
+            DynamicImage::new_luma_a8(bounds.width() as _, bounds.height() as _).to_luma_alpha8();
+        glyph.draw_with(&mut rasterizer, |x, y, alpha| {
+            new_image.put_pixel(x, y, LumaA([128, (alpha * 255.0).round() as u8]))
+        });
+        compare_image!(new_image, reference_bytes);
+    }
+}
+
+/// Drawing another glyph from within a `draw` callback should work,
+/// i.e. not conflict with the thread-local pooled rasterizer.
+#[test]
+fn outline_draw_reentrant() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let expected = outline_draw(&font, 'w', 16.0);
+    let font = font.into_scaled(16.0);
+    let w = font.outline_glyph(font.scaled_glyph('w')).unwrap();
+
+    let mut drawn_outer = false;
+    w.draw(|_, _, _| {
+        if !drawn_outer {
+            drawn_outer = true;
+            let mut inner =
+                DynamicImage::new_luma_a8(expected.width(), expected.height()).to_luma_alpha8();
+            w.draw(|x, y, alpha| {
+                inner.put_pixel(x, y, LumaA([128, (alpha * 255.0).round() as u8]))
+            });
+            assert_eq!(inner, expected);
+        }
+    });
+    assert!(drawn_outer);
+}
+
+#[test]
+fn reference_outline_draw_lcd_rgb_ttf_w() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let new_image = outline_draw_lcd(font, 'w', 32.0, SubpixelLayout::Rgb);
+    new_image.save(temp_path("new_lcd_rgb_ttf_w.png")).unwrap();
+    compare_rgb_image(&new_image, include_bytes!("reference_lcd_rgb_ttf_w.png"));
+}
+
+#[test]
+fn reference_outline_draw_lcd_vrgb_ttf_w() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let new_image = outline_draw_lcd(font, 'w', 32.0, SubpixelLayout::VRgb);
+    new_image.save(temp_path("new_lcd_vrgb_ttf_w.png")).unwrap();
+    compare_rgb_image(&new_image, include_bytes!("reference_lcd_vrgb_ttf_w.png"));
+}
+
+/// BGR LCD drawing should be RGB drawing with red & blue swapped.
+#[test]
+fn outline_draw_lcd_bgr_eq_swapped_rgb() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let rgb = outline_draw_lcd(&font, 'ę', 40.0, SubpixelLayout::Rgb);
+    let bgr = outline_draw_lcd(&font, 'ę', 40.0, SubpixelLayout::Bgr);
+    assert_eq!(rgb.dimensions(), bgr.dimensions());
+    for (Rgb([r, g, b]), bgr) in rgb.pixels().zip(bgr.pixels()) {
+        assert_eq!(Rgb([*b, *g, *r]), *bgr);
+    }
+}
+
+/// `accumulate_into` should produce the same alphas as `for_each_pixel` (within rounding error).
+#[test]
+fn accumulate_into_ttf_biohazard() {
+    let rasterizer = dev::rasterize_ttf_biohazard();
+    let (w, h) = rasterizer.dimensions();
+    let mut coverage = vec![0.0; w * h];
+    rasterizer.accumulate_into(&mut coverage);
+
+    rasterizer.for_each_pixel(|idx, alpha| {
+        assert_relative_eq!(coverage[idx], alpha, epsilon = 1e-5);
+    });
+}
+
 fn outline_draw<F: Font>(font: F, c: char, scale: f32) -> image::GrayAlphaImage {
+    outline_draw_with(font, c, scale, |glyph| glyph)
+}
+
+/// Draws an outlined glyph after applying `configure`, e.g. setting the fill rule.
+fn outline_draw_with<F: Font>(
+    font: F,
+    c: char,
+    scale: f32,
+    configure: impl FnOnce(OutlinedGlyph) -> OutlinedGlyph,
+) -> image::GrayAlphaImage {
     let font = font.into_scaled(scale);
 
-    let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+    let glyph = configure(font.outline_glyph(font.scaled_glyph(c)).unwrap());
     let bounds = glyph.px_bounds();
 
     let mut glyph_image =
@@ -158,6 +446,44 @@ fn outline_draw<F: Font>(font: F, c: char, scale: f32) -> image::GrayAlphaImage
     glyph_image
 }
 
+/// Draws an outlined glyph for an LCD `layout` as black text on a white background.
+fn outline_draw_lcd<F: Font>(
+    font: F,
+    c: char,
+    scale: f32,
+    layout: SubpixelLayout,
+) -> image::RgbImage {
+    let font = font.into_scaled(scale);
+    let glyph = font.outline_glyph(font.scaled_glyph(c)).unwrap();
+    let bounds = glyph.lcd_px_bounds(layout);
+
+    let mut image = image::RgbImage::from_pixel(
+        bounds.width() as _,
+        bounds.height() as _,
+        Rgb([255, 255, 255]),
+    );
+    glyph.draw_lcd(layout, LcdFilter::DEFAULT, |x, y, coverage| {
+        let channels = coverage.map(|c| ((1.0 - c) * 255.0).round() as u8);
+        image.put_pixel(x, y, Rgb(channels));
+    });
+    image
+}
+
+fn compare_rgb_image(new_image: &image::RgbImage, reference_bytes: &[u8]) {
+    let reference = image::load(Cursor::new(reference_bytes), image::ImageFormat::Png)
+        .expect("!image::load")
+        .to_rgb8();
+
+    assert_eq!(reference.dimensions(), new_image.dimensions());
+    for (x, y, px) in reference.enumerate_pixels() {
+        assert_eq!(
+            px,
+            new_image.get_pixel(x, y),
+            "unexpected rgb difference at ({x}, {y})"
+        );
+    }
+}
+
 fn draw_grey_image(rasterizer: Rasterizer) -> image::GrayAlphaImage {
     let (w, h) = rasterizer.dimensions();
     let mut glyph_image = DynamicImage::new_luma_a8(w as _, h as _).to_luma_alpha8();
@@ -0,0 +1,96 @@
+use ab_glyph::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+
+/// Memory-mapped-like data counting drops.
+struct TrackedData {
+    data: Box<[u8]>,
+    drops: Arc<AtomicUsize>,
+}
+
+impl AsRef<[u8]> for TrackedData {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Drop for TrackedData {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn arc_data_shared() {
+    let data: Arc<[u8]> = EXO2_OTF.into();
+    let fonts: Vec<FontArc> = (0..3)
+        .map(|_| FontArc::try_from_data(Arc::clone(&data)).unwrap())
+        .collect();
+    assert_eq!(Arc::strong_count(&data), 4);
+
+    for font in &fonts {
+        assert_eq!(font.font_data().as_ptr(), data.as_ptr());
+        assert_eq!(font.glyph_id('s'), GlyphId(56));
+    }
+    drop(fonts);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+/// Custom data should live as long as the font & its clones.
+#[test]
+fn custom_data_lifetime() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let font = FontArc::try_from_data(TrackedData {
+        data: DEJA_VU_MONO.into(),
+        drops: Arc::clone(&drops),
+    })
+    .unwrap();
+    let font_clone = font.clone();
+    drop(font);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+    let q = font_clone
+        .outline_glyph(font_clone.glyph_id('q').with_scale(20.0))
+        .unwrap();
+    let mut area = 0.0;
+    q.draw(|_, _, c| area += c);
+    assert!(area > 10.0, "{area}");
+
+    drop(font_clone);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn data_matches_other_fonts() {
+    let font = FontArc::try_from_data(EXO2_OTF.to_vec()).unwrap();
+    let font_ref = FontRef::try_from_slice(EXO2_OTF).unwrap();
+
+    assert_eq!(font.font_data(), EXO2_OTF);
+    assert_eq!(font.units_per_em(), font_ref.units_per_em());
+    assert_eq!(font.ascent_unscaled(), font_ref.ascent_unscaled());
+    let id = font.glyph_id('ę');
+    assert_eq!(font.h_advance_unscaled(id), font_ref.h_advance_unscaled(id));
+    let (outline, ref_outline) = (font.outline(id).unwrap(), font_ref.outline(id).unwrap());
+    assert_eq!(outline.bounds, ref_outline.bounds);
+    assert_eq!(outline.curves.len(), ref_outline.curves.len());
+}
+
+#[test]
+fn data_errors() {
+    let err = FontArc::try_from_data(&b"not a font"[..]).unwrap_err();
+    assert_eq!(err, FontError::UnknownMagic);
+
+    let err = FontArc::try_from_data_and_index(EXO2_OTF, 1).unwrap_err();
+    assert_eq!(
+        err,
+        FontError::FaceIndexOutOfBounds {
+            index: 1,
+            face_count: 1
+        }
+    );
+}
@@ -0,0 +1,178 @@
+use ab_glyph::*;
+use approx::assert_relative_eq;
+use std::f32::consts::PI;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns an outline of a single contour of `curves`.
+fn outline(curves: Vec<OutlineCurve>) -> Outline {
+    Outline::new(Rect::default(), curves, vec![0])
+}
+
+/// Returns the total coverage of `outline` drawn at 1:1 scale with accurate curves.
+fn drawn_area(outline: Outline) -> f32 {
+    let glyph = OutlinedGlyph::new(
+        GlyphId(0).with_scale(1.0),
+        outline,
+        PxScaleFactor {
+            horizontal: 1.0,
+            vertical: 1.0,
+        },
+    )
+    .with_flattening_tolerance(0.001);
+    let mut area = 0.0;
+    glyph.draw(|_, _, c| area += c);
+    area
+}
+
+fn square(size: f32) -> Outline {
+    outline(vec![
+        OutlineCurve::Line(point(0.0, 0.0), point(0.0, size)),
+        OutlineCurve::Line(point(0.0, size), point(size, size)),
+        OutlineCurve::Line(point(size, size), point(size, 0.0)),
+        OutlineCurve::Line(point(size, 0.0), point(0.0, 0.0)),
+    ])
+}
+
+#[test]
+fn stroke_square_joins() {
+    let stroke = Stroke::new(2.0);
+    let miter = square(10.0).stroke(stroke);
+    assert_eq!(
+        miter.bounds,
+        Rect {
+            min: point(-1.0, 11.0),
+            max: point(11.0, -1.0),
+        }
+    );
+    assert_relative_eq!(drawn_area(miter), 12.0 * 12.0 - 8.0 * 8.0, epsilon = 1e-3);
+
+    // corners cut by half a pixel
+    let bevel = square(10.0).stroke(stroke.with_join(LineJoin::Bevel));
+    assert_relative_eq!(drawn_area(bevel), 80.0 - 4.0 * 0.5, epsilon = 1e-3);
+
+    // corners with quarter circles of radius 1
+    let round = square(10.0).stroke(stroke.with_join(LineJoin::Round));
+    assert_relative_eq!(drawn_area(round), 80.0 - 4.0 + PI, epsilon = 1e-2);
+}
+
+/// Sharp corners should be beveled beyond the miter limit.
+#[test]
+fn stroke_miter_limit() {
+    // ~11.4° spike, miter ratio ~10
+    let spike = outline(vec![
+        OutlineCurve::Line(point(0.0, 0.0), point(20.0, 2.0)),
+        OutlineCurve::Line(point(20.0, 2.0), point(0.0, 4.0)),
+        OutlineCurve::Line(point(0.0, 4.0), point(0.0, 0.0)),
+    ]);
+    let stroke = Stroke::new(2.0);
+
+    let mitered = spike.stroke(stroke.with_miter_limit(11.0));
+    assert!(mitered.bounds.max.x > 29.0, "{:?}", mitered.bounds);
+
+    let beveled = spike.stroke(stroke);
+    assert!(beveled.bounds.max.x < 21.0, "{:?}", beveled.bounds);
+}
+
+#[test]
+fn stroke_open_line_caps() {
+    let line = || outline(vec![OutlineCurve::Line(point(2.0, 2.0), point(12.0, 2.0))]);
+    let stroke = Stroke::new(2.0);
+
+    assert_relative_eq!(drawn_area(line().stroke(stroke)), 20.0, epsilon = 1e-3);
+    let square = line().stroke(stroke.with_cap(LineCap::Square));
+    assert_relative_eq!(drawn_area(square), 24.0, epsilon = 1e-3);
+    let round = line().stroke(stroke.with_cap(LineCap::Round));
+    assert_relative_eq!(drawn_area(round), 20.0 + PI, epsilon = 1e-2);
+}
+
+/// A stroked circle should be a ring with the correct area.
+#[test]
+fn stroke_curves() {
+    // circle of radius 10 centred at (12, 12) from 4 cubic arcs
+    let k = 10.0 * 4.0 / 3.0 * (PI / 8.0).tan();
+    let c = point(12.0, 12.0);
+    let circle = outline(
+        (0..4)
+            .map(|i| {
+                let a = i as f32 * PI / 2.0;
+                let b = a + PI / 2.0;
+                let (u, w) = (point(a.cos(), a.sin()), point(b.cos(), b.sin()));
+                OutlineCurve::Cubic(
+                    c + u * 10.0,
+                    c + u * 10.0 + point(-u.y, u.x) * k,
+                    c + w * 10.0 - point(-w.y, w.x) * k,
+                    c + w * 10.0,
+                )
+            })
+            .collect(),
+    );
+    let ring = circle.stroke(Stroke::new(4.0));
+    assert_relative_eq!(
+        drawn_area(ring),
+        PI * (12.0 * 12.0 - 8.0 * 8.0),
+        epsilon = 0.5
+    );
+}
+
+/// Stroked glyph coverage should cover pixels near the outline & nothing further.
+#[test]
+fn stroked_glyph_near_outline() {
+    for (font_data, c) in [
+        (DEJA_VU_MONO, 'w'),
+        // touching contours
+        (DEJA_VU_MONO, '≑'),
+        (EXO2_OTF, 'ę'),
+        (EXO2_OTF, '&'),
+    ] {
+        let font = FontRef::try_from_slice(font_data).unwrap();
+        let glyph = font
+            .outline_glyph(font.glyph_id(c).with_scale(60.0))
+            .unwrap();
+        let width = 4.0;
+        let stroked = glyph.stroked(Stroke::new(width).with_join(LineJoin::Round));
+
+        let spread = width;
+        let sdf_bounds = glyph.sdf_px_bounds(spread);
+        let (sdf_w, sdf_h) = (sdf_bounds.width() as usize, sdf_bounds.height() as usize);
+        let mut sdf = vec![0.0; sdf_w * sdf_h];
+        glyph.draw_sdf(spread, |x, y, d| sdf[y as usize * sdf_w + x as usize] = d);
+
+        let offset = stroked.px_bounds().min - sdf_bounds.min;
+        assert!(offset.x >= 0.0 && offset.y >= 0.0, "{offset:?}");
+        let mut covered = vec![0.0; sdf_w * sdf_h];
+        stroked.draw(|x, y, c| {
+            let idx = (y as f32 + offset.y) as usize * sdf_w + (x as f32 + offset.x) as usize;
+            covered[idx] = c;
+        });
+
+        for (idx, (d, c)) in sdf.iter().zip(&covered).enumerate() {
+            let (x, y) = (idx % sdf_w, idx / sdf_w);
+            if d.abs() < width / 2.0 - 0.75 {
+                assert!(*c > 0.999, "{c:?} ({x}, {y}) distance {d} coverage {c}");
+            } else if d.abs() > width / 2.0 + 0.75 {
+                assert!(*c < 0.001, "{c:?} ({x}, {y}) distance {d} coverage {c}");
+            }
+        }
+    }
+}
+
+/// Each closed contour should be stroked by its own inner & outer ring.
+#[test]
+fn stroke_contour_rings() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let outline = font.outline(font.glyph_id('≑')).unwrap();
+    assert_eq!(outline.contour_starts().len(), 5);
+
+    let stroked = outline.stroke(Stroke::new(20.0));
+    assert_eq!(stroked.contour_starts().len(), 10);
+    assert_eq!(stroked.contours().len(), 10);
+}
+
+#[test]
+fn stroke_zero_width() {
+    let stroked = square(10.0).stroke(Stroke::new(0.0));
+    assert!(stroked.curves.is_empty());
+    assert_eq!(stroked.bounds, Rect::default());
+}
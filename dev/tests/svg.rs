@@ -0,0 +1,152 @@
+use ab_glyph::*;
+
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+
+/// Returns the numbers in svg path data `d`.
+fn path_numbers(d: &str) -> Vec<f32> {
+    d.split(|c: char| c.is_ascii_alphabetic() || c == ' ')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn outline_svg_path() {
+    let outline = Outline::new(
+        Rect {
+            min: point(0.0, 10.0),
+            max: point(10.0, 0.0),
+        },
+        vec![
+            OutlineCurve::Line(point(0.0, 0.0), point(0.0, 10.0)),
+            OutlineCurve::Quad(point(0.0, 10.0), point(5.0, 15.0), point(10.0, 10.0)),
+            OutlineCurve::Cubic(
+                point(10.0, 10.0),
+                point(10.0, 6.0),
+                point(12.0, 3.0),
+                point(10.0, 0.0),
+            ),
+            OutlineCurve::Line(point(10.0, 0.0), point(0.0, 0.0)),
+            OutlineCurve::Line(point(2.0, 2.0), point(2.0, 4.0)),
+            OutlineCurve::Line(point(2.0, 4.0), point(4.0, 2.0)),
+        ],
+        vec![0, 4],
+    );
+    let scale_factor = PxScaleFactor {
+        horizontal: 0.5,
+        vertical: 0.25,
+    };
+    assert_eq!(
+        outline.svg_path(scale_factor, point(100.0, 50.0)),
+        "M100 50L100 47.5Q102.5 46.25 105 47.5C105 48.5 106 49.25 105 50Z\
+         M101 49.5L101 49L102 49.5Z"
+    );
+}
+
+/// Glyph paths should be in the same pixel coordinates as `px_bounds`.
+#[test]
+fn outlined_glyph_svg_path_in_px_bounds() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let glyph = font
+        .outline_glyph(
+            font.glyph_id('ę')
+                .with_scale_and_position(40.0, point(10.3, 60.6)),
+        )
+        .unwrap();
+
+    for transformed in [
+        glyph.clone(),
+        glyph.clone().with_transform(Transform::rotate(0.5)),
+        glyph.clone().with_transform(Transform::scale(-1.0, 2.0)),
+    ] {
+        let bounds = transformed.px_bounds();
+        let d = transformed.svg_path();
+        let numbers = path_numbers(&d);
+        assert!(!numbers.is_empty());
+        for xy in numbers.chunks(2) {
+            assert!(
+                (bounds.min.x..=bounds.max.x).contains(&xy[0])
+                    && (bounds.min.y..=bounds.max.y).contains(&xy[1]),
+                "{xy:?} outside {bounds:?}"
+            );
+        }
+        // bounds are fit to the curve control points
+        let min_x = numbers.chunks(2).map(|xy| xy[0]).fold(f32::MAX, f32::min);
+        let max_y = numbers.chunks(2).map(|xy| xy[1]).fold(f32::MIN, f32::max);
+        assert!(min_x - bounds.min.x < 1.0, "{min_x} {bounds:?}");
+        assert!(bounds.max.y - max_y < 1.0, "{max_y} {bounds:?}");
+    }
+}
+
+#[test]
+fn outlined_glyph_svg_path_eq_outline() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let position = point(3.0, 30.0);
+    let glyph = font.glyph_id('B').with_scale_and_position(24.0, position);
+    let scale_factor = font.as_scaled(24.0).scale_factor();
+
+    let outlined = font.outline_glyph(glyph.clone()).unwrap();
+    let outline = font.outline(glyph.id).unwrap();
+    let d = outlined.svg_path();
+    assert_eq!(d, outline.svg_path(scale_factor, position));
+    // 'B' has 3 contours
+    assert_eq!(d.matches('M').count(), 3);
+    assert_eq!(d.matches('Z').count(), 3);
+
+    // '≑' has 5 contours, 2 touching
+    let glyph = font.glyph_id('≑').with_scale_and_position(24.0, position);
+    let d = font.outline_glyph(glyph).unwrap().svg_path();
+    assert_eq!(d.matches('M').count(), 5);
+    assert_eq!(d.matches('Z').count(), 5);
+}
+
+#[test]
+fn glyphs_svg_document() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let scaled = font.as_scaled(30.0);
+    let mut caret = point(5.0, scaled.ascent());
+    let glyphs: Vec<_> = "a b"
+        .chars()
+        .map(|c| {
+            let glyph = scaled.scaled_glyph(c);
+            let glyph = Glyph {
+                position: caret,
+                ..glyph
+            };
+            caret.x += scaled.h_advance(glyph.id);
+            glyph
+        })
+        .collect();
+
+    let svg = svg_document(&font, &glyphs);
+    let a = font.outline_glyph(glyphs[0].clone()).unwrap();
+    let b = font.outline_glyph(glyphs[2].clone()).unwrap();
+    let (a_bounds, b_bounds) = (a.px_bounds(), b.px_bounds());
+    let (x, y) = (a_bounds.min.x, a_bounds.min.y.min(b_bounds.min.y));
+    let w = b_bounds.max.x - x;
+    let h = a_bounds.max.y.max(b_bounds.max.y) - y;
+
+    assert_eq!(
+        svg,
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"{x} {y} {w} {h}\">\n\
+             <path d=\"{}\"/>\n\
+             <path d=\"{}\"/>\n\
+             </svg>\n",
+            a.svg_path(),
+            b.svg_path(),
+        )
+    );
+}
+
+#[test]
+fn empty_svg_document() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let space = font.glyph_id(' ').with_scale(20.0);
+    assert_eq!(
+        svg_document(&font, &[space]),
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\" viewBox=\"0 0 0 0\">\n</svg>\n"
+    );
+}
@@ -0,0 +1,220 @@
+use ab_glyph::*;
+use approx::assert_relative_eq;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const OPENS_SANS_ITALIC: &[u8] = include_bytes!("../fonts/OpenSans-Italic.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns the total coverage of glyph `c` drawn at `scale`.
+fn drawn_area<F: Font>(font: F, c: char, scale: f32) -> f32 {
+    let glyph = font
+        .outline_glyph(font.glyph_id(c).with_scale(scale))
+        .unwrap();
+    let mut area = 0.0;
+    glyph.draw(|_, _, c| area += c);
+    area
+}
+
+fn points(outline: &Outline) -> Vec<Point> {
+    outline
+        .curves
+        .iter()
+        .flat_map(|curve| match *curve {
+            OutlineCurve::Line(p0, p1) => vec![p0, p1],
+            OutlineCurve::Quad(p0, p1, p2) => vec![p0, p1, p2],
+            OutlineCurve::Cubic(p0, p1, p2, p3) => vec![p0, p1, p2, p3],
+        })
+        .collect()
+}
+
+#[test]
+fn no_synthesis_eq_font() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let synthetic = SyntheticFont::new(font.clone());
+    let id = font.glyph_id('ę');
+
+    assert_eq!(
+        synthetic.h_advance_unscaled(id),
+        font.h_advance_unscaled(id)
+    );
+    assert_eq!(synthetic.italic_angle(), 0.0);
+    let (outline, synthetic_outline) = (font.outline(id).unwrap(), synthetic.outline(id).unwrap());
+    assert_eq!(synthetic_outline.bounds, outline.bounds);
+    assert_eq!(synthetic_outline.curves.len(), outline.curves.len());
+}
+
+#[test]
+fn embolden_metrics() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let bold = SyntheticFont::new(font.clone()).with_embolden(0.05);
+    let strength = 0.05 * font.units_per_em().unwrap();
+    assert_eq!(bold.embolden(), 0.05);
+
+    let (l, i) = (font.glyph_id('l'), font.glyph_id('i'));
+    assert_eq!(
+        bold.h_advance_unscaled(l),
+        font.h_advance_unscaled(l) + strength
+    );
+    assert_eq!(
+        bold.h_side_bearing_unscaled(l),
+        font.h_side_bearing_unscaled(l)
+    );
+    assert_eq!(bold.kern_unscaled(l, i), font.kern_unscaled(l, i));
+
+    assert_eq!(bold.ascent_unscaled(), font.ascent_unscaled());
+    assert_eq!(bold.descent_unscaled(), font.descent_unscaled());
+
+    // rectilinear glyph grows by the strength keeping the left & bottom edges
+    let bounds = font.outline(l).unwrap().bounds;
+    let bold_bounds = bold.outline(l).unwrap().bounds;
+    assert_relative_eq!(bold_bounds.min.x, bounds.min.x, epsilon = 1e-3);
+    assert_relative_eq!(bold_bounds.max.y, bounds.max.y, epsilon = 1e-3);
+    assert_relative_eq!(bold_bounds.max.x, bounds.max.x + strength, epsilon = 1e-3);
+    assert_relative_eq!(bold_bounds.min.y, bounds.min.y + strength, epsilon = 1e-3);
+
+    // zero advance combining marks are not moved by emboldening
+    let font = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let bold = SyntheticFont::new(&font).with_embolden(0.05);
+    let acute = font.glyph_id('\u{301}');
+    assert_eq!(font.h_advance_unscaled(acute), 0.0);
+    assert_eq!(bold.h_advance_unscaled(acute), 0.0);
+}
+
+/// Negative strengths cannot thin glyphs, so should not embolden at all.
+#[test]
+fn negative_embolden() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let id = font.glyph_id('l');
+    for strength in [-0.05, f32::NAN] {
+        let thin = SyntheticFont::new(font.clone()).with_embolden(strength);
+        assert_eq!(thin.embolden(), 0.0);
+        assert_eq!(thin.h_advance_unscaled(id), font.h_advance_unscaled(id));
+        assert_eq!(thin.v_advance_unscaled(id), font.v_advance_unscaled(id));
+        assert_eq!(
+            points(&thin.outline(id).unwrap()),
+            points(&font.outline(id).unwrap())
+        );
+    }
+}
+
+/// Emboldened glyphs should draw more coverage without holes where the
+/// stroke overlaps the fill.
+#[test]
+fn embolden_draw() {
+    for (font_data, c) in [
+        (DEJA_VU_MONO, 'l'),
+        (EXO2_OTF, 'ę'),
+        (OPENS_SANS_ITALIC, 'o'),
+    ] {
+        let font = FontRef::try_from_slice(font_data).unwrap();
+        let bold = SyntheticFont::new(font.clone()).with_embolden(1.0 / 24.0);
+        let scale = 100.0;
+
+        let regular = font
+            .outline_glyph(font.glyph_id(c).with_scale(scale))
+            .unwrap();
+        let emboldened = bold
+            .outline_glyph(bold.glyph_id(c).with_scale(scale))
+            .unwrap();
+
+        // bold glyphs are grown by half the strength then moved right & up by half
+        let half = (bold.as_scaled(scale).h_advance(bold.glyph_id(c))
+            - font.as_scaled(scale).h_advance(font.glyph_id(c)))
+            / 2.0;
+        assert!(half > 1.0, "{half}");
+        let bounds = emboldened.px_bounds();
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        let mut bold_coverage = vec![0.0; w * h];
+        emboldened.draw(|x, y, c| bold_coverage[y as usize * w + x as usize] = c);
+
+        // pixels covered by the regular glyph should be covered when moved
+        let offset = regular.px_bounds().min - bounds.min + point(half, -half);
+        regular.draw(|x, y, cov| {
+            if cov > 0.999 {
+                let bx = (x as f32 + offset.x).floor() as usize;
+                let by = (y as f32 + offset.y).floor() as usize;
+                for (bx, by) in [(bx, by), (bx + 1, by), (bx, by + 1), (bx + 1, by + 1)] {
+                    let bold_cov = bold_coverage[by * w + bx];
+                    assert!(
+                        bold_cov > 0.999,
+                        "{c:?} ({bx}, {by}) bold coverage {bold_cov}"
+                    );
+                }
+            }
+        });
+
+        assert!(drawn_area(&bold, c, scale) > drawn_area(&font, c, scale));
+    }
+}
+
+/// Emboldened outlines should record the glyph contours followed by the stroke rings.
+#[test]
+fn embolden_contours() {
+    for (font_data, c, contour_count) in [
+        (DEJA_VU_MONO, 'l', 1),
+        (DEJA_VU_MONO, '≑', 5),
+        (EXO2_OTF, 'ę', 2),
+    ] {
+        let font = FontRef::try_from_slice(font_data).unwrap();
+        let bold = SyntheticFont::new(font.clone()).with_embolden(1.0 / 24.0);
+        let outline = bold.outline(bold.glyph_id(c)).unwrap();
+        let starts = outline.contour_starts();
+        assert_eq!(starts.len(), contour_count * 3, "{c:?}");
+
+        // each recorded contour should be closed & contiguous
+        let ends = starts[1..].iter().copied().chain([outline.curves.len()]);
+        for (start, end) in starts.iter().copied().zip(ends) {
+            let contour = &outline.curves[start..end];
+            for (a, b) in contour.iter().zip(contour.iter().cycle().skip(1)) {
+                let a_end = match *a {
+                    OutlineCurve::Line(_, p) | OutlineCurve::Quad(.., p) => p,
+                    OutlineCurve::Cubic(.., p) => p,
+                };
+                let (OutlineCurve::Line(b_start, _)
+                | OutlineCurve::Quad(b_start, ..)
+                | OutlineCurve::Cubic(b_start, ..)) = *b;
+                assert_eq!(a_end, b_start, "{c:?} contour {start} not contiguous");
+            }
+        }
+    }
+}
+
+#[test]
+fn oblique() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let oblique = SyntheticFont::new(font.clone()).with_oblique_angle(-12.0);
+    assert_eq!(oblique.oblique_angle(), -12.0);
+    assert_eq!(oblique.italic_angle(), -12.0);
+
+    let l = font.glyph_id('l');
+    assert_eq!(oblique.h_advance_unscaled(l), font.h_advance_unscaled(l));
+
+    // outline points are slanted right proportionally to their height
+    let skew = 12_f32.to_radians().tan();
+    let outline = font.outline(l).unwrap();
+    let oblique_outline = oblique.outline(l).unwrap();
+    let (points, oblique_points) = (points(&outline), points(&oblique_outline));
+    assert_eq!(points.len(), oblique_points.len());
+    for (p, o) in points.iter().zip(&oblique_points) {
+        assert_relative_eq!(o.x, p.x + p.y * skew, epsilon = 1e-3);
+        assert_eq!(o.y, p.y);
+    }
+
+    // italic fonts add any existing angle
+    let italic = FontRef::try_from_slice(OPENS_SANS_ITALIC).unwrap();
+    let more_italic = SyntheticFont::new(italic).with_oblique_angle(-5.0);
+    assert_eq!(more_italic.italic_angle(), -17.0);
+}
+
+#[test]
+fn synthetic_font_arc() {
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let bold_italic = FontArc::new(
+        SyntheticFont::new(font.clone())
+            .with_embolden(1.0 / 24.0)
+            .with_oblique_angle(-12.0),
+    );
+    assert_eq!(bold_italic.italic_angle(), -12.0);
+    assert_eq!(bold_italic.font_data(), font.font_data());
+    assert!(drawn_area(&bold_italic, 'W', 40.0) > drawn_area(&font, 'W', 40.0));
+}
@@ -0,0 +1,165 @@
+use ab_glyph::*;
+use approx::assert_relative_eq;
+
+const DEJA_VU_MONO: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const EXO2_OTF: &[u8] = include_bytes!("../fonts/Exo2-Light.otf");
+
+/// Returns `(width, height, coverage)` drawn by `glyph`.
+fn draw(glyph: &OutlinedGlyph) -> (u32, u32, Vec<f32>) {
+    let bounds = glyph.px_bounds();
+    let (w, h) = (bounds.width() as u32, bounds.height() as u32);
+    let mut coverage = vec![0.0; (w * h) as usize];
+    glyph.draw(|x, y, c| coverage[(y * w + x) as usize] = c);
+    (w, h, coverage)
+}
+
+fn outline_glyph(font_data: &[u8], c: char, scale: f32, position: Point) -> OutlinedGlyph {
+    let font = FontRef::try_from_slice(font_data).unwrap();
+    let glyph = font.glyph_id(c).with_scale_and_position(scale, position);
+    font.outline_glyph(glyph).unwrap()
+}
+
+#[test]
+fn identity_transform() {
+    let glyph = outline_glyph(EXO2_OTF, 'ę', 40.0, point(10.3, 20.6));
+    let transformed = glyph.clone().with_transform(Transform::IDENTITY);
+    assert_eq!(transformed.px_bounds(), glyph.px_bounds());
+    assert_eq!(draw(&transformed), draw(&glyph));
+}
+
+/// Whole pixel translations should move bounds without changing coverage.
+#[test]
+fn translate_transform() {
+    let glyph = outline_glyph(EXO2_OTF, 'ę', 40.0, point(10.3, 20.6));
+    let transformed = glyph
+        .clone()
+        .with_transform(Transform::translate(5.0, -3.0));
+
+    let bounds = glyph.px_bounds();
+    assert_eq!(
+        transformed.px_bounds(),
+        Rect {
+            min: bounds.min + point(5.0, -3.0),
+            max: bounds.max + point(5.0, -3.0),
+        }
+    );
+    let (w, h, coverage) = draw(&glyph);
+    let (tw, th, transformed_coverage) = draw(&transformed);
+    assert_eq!((tw, th), (w, h));
+    for (c, tc) in coverage.iter().zip(&transformed_coverage) {
+        assert_relative_eq!(c, tc, epsilon = 1e-5);
+    }
+}
+
+/// Scaling by transform should draw the same as the equivalent px scale.
+#[test]
+fn scale_transform_eq_px_scale() {
+    let glyph = outline_glyph(EXO2_OTF, 'ę', 20.0, point(0.0, 0.0));
+    let transformed = glyph.with_transform(Transform::scale(2.0, 3.0));
+    let font = FontRef::try_from_slice(EXO2_OTF).unwrap();
+    let scaled = font
+        .outline_glyph(font.glyph_id('ę').with_scale(PxScale { x: 40.0, y: 60.0 }))
+        .unwrap();
+
+    assert_eq!(transformed.px_bounds(), scaled.px_bounds());
+    let (_, _, coverage) = draw(&scaled);
+    let (_, _, transformed_coverage) = draw(&transformed);
+    for (c, tc) in coverage.iter().zip(&transformed_coverage) {
+        assert_relative_eq!(c, tc, epsilon = 1e-4);
+    }
+}
+
+/// Mirroring around the glyph position should draw mirrored pixels.
+#[test]
+fn mirror_transform() {
+    let glyph = outline_glyph(DEJA_VU_MONO, 'R', 30.0, point(0.0, 0.0));
+    let mirrored = glyph.clone().with_transform(Transform::scale(-1.0, 1.0));
+
+    let bounds = glyph.px_bounds();
+    assert_eq!(
+        mirrored.px_bounds(),
+        Rect {
+            min: point(-bounds.max.x, bounds.min.y),
+            max: point(-bounds.min.x, bounds.max.y),
+        }
+    );
+    let (w, h, coverage) = draw(&glyph);
+    let (_, _, mirrored_coverage) = draw(&mirrored);
+    for y in 0..h {
+        for x in 0..w {
+            let c = coverage[(y * w + x) as usize];
+            let mc = mirrored_coverage[(y * w + w - 1 - x) as usize];
+            assert_relative_eq!(c, mc, epsilon = 1e-4);
+        }
+    }
+}
+
+/// Rotation & skew should preserve the total drawn area.
+#[test]
+fn rotate_skew_transform_area() {
+    let glyph = outline_glyph(EXO2_OTF, 'ę', 60.0, point(3.5, 70.2));
+    let area = draw(&glyph).2.iter().sum::<f32>();
+
+    for transform in [
+        Transform::rotate(0.4),
+        Transform::rotate(-2.0),
+        Transform::skew(-0.3, 0.0),
+        Transform::skew(0.2, 0.1).then(Transform::rotate(1.0)),
+    ] {
+        let transformed = glyph.clone().with_transform(transform);
+        let expected = area * (transform.xx * transform.yy - transform.xy * transform.yx);
+        let transformed_area = draw(&transformed).2.iter().sum::<f32>();
+        assert_relative_eq!(transformed_area, expected, max_relative = 1e-3);
+    }
+}
+
+/// Transformed px_bounds should tightly fit the drawn pixels.
+#[test]
+fn rotate_transform_px_bounds_fit() {
+    let glyph = outline_glyph(DEJA_VU_MONO, 'L', 40.0, point(0.0, 0.0));
+    let rotated = glyph.with_transform(Transform::rotate(core::f32::consts::FRAC_PI_4));
+    let (w, h, coverage) = draw(&rotated);
+    let covered = |x: u32, y: u32| coverage[(y * w + x) as usize] > 0.0;
+    assert!((0..h).any(|y| covered(0, y)));
+    assert!((0..h).any(|y| covered(w - 1, y)));
+    assert!((0..w).any(|x| covered(x, 0)));
+    assert!((0..w).any(|x| covered(x, h - 1)));
+}
+
+#[test]
+fn outline_transform_bounds() {
+    let font = FontRef::try_from_slice(DEJA_VU_MONO).unwrap();
+    let outline = font.outline(font.glyph_id('L')).unwrap();
+    let Rect { min, max } = outline.bounds;
+
+    // rotate 180°
+    let mut rotated = outline.clone();
+    rotated.transform(Transform::scale(-1.0, -1.0));
+    assert_eq!(
+        rotated.bounds,
+        Rect {
+            min: point(-max.x, -max.y),
+            max: point(-min.x, -min.y),
+        }
+    );
+
+    let mut translated = outline;
+    translated.transform(Transform::translate(100.0, 50.0));
+    assert_eq!(
+        translated.bounds,
+        Rect {
+            min: min + point(100.0, 50.0),
+            max: max + point(100.0, 50.0),
+        }
+    );
+}
+
+#[test]
+fn transform_then() {
+    let transform = Transform::scale(2.0, 3.0)
+        .then(Transform::rotate(core::f32::consts::FRAC_PI_2))
+        .then(Transform::translate(1.0, 2.0));
+    let p = transform.transform_point(point(1.0, 1.0));
+    assert_relative_eq!(p.x, -2.0, epsilon = 1e-6);
+    assert_relative_eq!(p.y, 4.0, epsilon = 1e-6);
+}
@@ -0,0 +1,90 @@
+use crate::{
+    ttfp::{SharedData, SharedFont},
+    FontArc, FontError,
+};
+use alloc::sync::Arc;
+use core::fmt;
+
+/// Font collection (.ttc, .otc) data, or single font data, shared by each of its faces.
+///
+/// Faces are loaded as [`FontArc`]s referencing the same data without copying.
+/// Data may be any shared owned bytes, e.g. `Vec<u8>`, `Arc<[u8]>`, `bytes::Bytes`
+/// or memory-mapped font files.
+///
+/// # Example
+/// ```
+/// use ab_glyph::{Font, FontCollection};
+///
+/// # fn main() -> Result<(), ab_glyph::FontError> {
+/// # let ttc_data = include_bytes!("../../dev/fonts/Exo2-Light.otf").to_vec();
+/// let collection = FontCollection::new(ttc_data);
+///
+/// // choose a face by name
+/// for font in collection.faces() {
+///     let font = font?;
+///     if font.subfamily_name().as_deref() == Some("Light") {
+///         // use font
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct FontCollection {
+    data: SharedData,
+    face_count: u32,
+}
+
+impl FontCollection {
+    /// Creates a collection from font collection or single font data.
+    ///
+    /// Data that is not a font collection is treated as a single face, which may fail to load.
+    pub fn new<D>(data: D) -> Self
+    where
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Self {
+            face_count: crate::ttfp::face_count(data.as_ref()),
+            data: Arc::new(data),
+        }
+    }
+
+    /// Returns the number of faces, `1` if not a font collection.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// let collection = FontCollection::new(&include_bytes!("../../dev/fonts/Exo2-Light.otf")[..]);
+    /// assert_eq!(collection.face_count(), 1);
+    /// ```
+    #[inline]
+    pub fn face_count(&self) -> u32 {
+        self.face_count
+    }
+
+    /// Loads face `index` sharing the collection data.
+    pub fn face(&self, index: u32) -> Result<FontArc, FontError> {
+        Ok(FontArc::from_shared(SharedFont::parse(
+            Arc::clone(&self.data),
+            index,
+        )?))
+    }
+
+    /// Returns an iterator loading each face in order, see [`FontCollection::face`].
+    pub fn faces(&self) -> impl Iterator<Item = Result<FontArc, FontError>> + '_ {
+        (0..self.face_count).map(|index| self.face(index))
+    }
+
+    /// Returns the shared collection data.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        (*self.data).as_ref()
+    }
+}
+
+impl fmt::Debug for FontCollection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FontCollection")
+            .field("face_count", &self.face_count)
+            .finish()
+    }
+}
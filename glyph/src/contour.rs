@@ -0,0 +1,241 @@
+use crate::{outlined::curves_bounds, point, Outline, OutlineCurve, Point};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Flag describing how a [`ContourPoint`] shapes its contour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PointKind {
+    /// Point the contour passes through.
+    OnCurve,
+    /// Off-curve control point of a quadratic Bézier curve.
+    QuadControl,
+    /// Off-curve control point of a cubic Bézier curve.
+    CubicControl,
+}
+
+/// A contour point with its [`PointKind`] flag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContourPoint {
+    pub point: Point,
+    pub kind: PointKind,
+}
+
+impl ContourPoint {
+    /// Point the contour passes through.
+    #[inline]
+    pub const fn on_curve(point: Point) -> Self {
+        Self {
+            point,
+            kind: PointKind::OnCurve,
+        }
+    }
+
+    /// Off-curve control point of a quadratic Bézier curve.
+    #[inline]
+    pub const fn quad_control(point: Point) -> Self {
+        Self {
+            point,
+            kind: PointKind::QuadControl,
+        }
+    }
+
+    /// Off-curve control point of a cubic Bézier curve.
+    #[inline]
+    pub const fn cubic_control(point: Point) -> Self {
+        Self {
+            point,
+            kind: PointKind::CubicControl,
+        }
+    }
+
+    /// Returns `true` if this is a [`PointKind::OnCurve`] point.
+    #[inline]
+    pub fn is_on_curve(&self) -> bool {
+        self.kind == PointKind::OnCurve
+    }
+}
+
+/// A closed contour of on & off-curve points, unscaled & unpositioned.
+///
+/// Points are connected in order & the last point connects back to the first.
+/// On-curve points are joined by straight lines, or curves using the off-curve
+/// control points between them. As in TrueType glyphs, consecutive quadratic
+/// controls imply an on-curve point midway between them. Cubic controls are
+/// expected in pairs.
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// // triangle with a curved bottom edge
+/// let contour = Contour {
+///     points: vec![
+///         ContourPoint::on_curve(point(0.0, 0.0)),
+///         ContourPoint::on_curve(point(5.0, 10.0)),
+///         ContourPoint::on_curve(point(10.0, 0.0)),
+///         ContourPoint::quad_control(point(5.0, -5.0)),
+///     ],
+/// };
+/// assert_eq!(contour.curves().len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Contour {
+    pub points: Vec<ContourPoint>,
+}
+
+impl Contour {
+    /// Returns the closed outline curves of this contour.
+    pub fn curves(&self) -> Vec<OutlineCurve> {
+        let points = &self.points;
+        let Some(last) = points.last() else {
+            return Vec::new();
+        };
+
+        // start at an on-curve point, or the implied point before the first control
+        let (start, first) = match points.iter().position(ContourPoint::is_on_curve) {
+            Some(idx) => (points[idx].point, idx + 1),
+            None => (midpoint(last.point, points[0].point), 0),
+        };
+
+        let mut builder = CurveBuilder {
+            last: start,
+            controls: Vec::with_capacity(2),
+            curves: Vec::with_capacity(points.len()),
+        };
+        for p in points[first..].iter().chain(&points[..first]) {
+            builder.push(*p);
+        }
+        if !builder.controls.is_empty() {
+            builder.end_at(start);
+        }
+        builder.curves
+    }
+}
+
+/// Builds outline curves from contour points.
+struct CurveBuilder {
+    last: Point,
+    controls: Vec<ContourPoint>,
+    curves: Vec<OutlineCurve>,
+}
+
+impl CurveBuilder {
+    fn push(&mut self, p: ContourPoint) {
+        if p.is_on_curve() {
+            self.end_at(p.point);
+            return;
+        }
+        let accepts = match self.controls.as_slice() {
+            [] => true,
+            [c] => c.kind == PointKind::CubicControl && p.kind == PointKind::CubicControl,
+            _ => false,
+        };
+        if !accepts {
+            let implied = midpoint(self.controls[self.controls.len() - 1].point, p.point);
+            self.end_at(implied);
+        }
+        self.controls.push(p);
+    }
+
+    /// Appends a curve from the last point to `end` using the pending controls.
+    fn end_at(&mut self, end: Point) {
+        let curve = match *self.controls.as_slice() {
+            [] => OutlineCurve::Line(self.last, end),
+            [c] => OutlineCurve::Quad(self.last, c.point, end),
+            [c1, c2, ..] => OutlineCurve::Cubic(self.last, c1.point, c2.point, end),
+        };
+        self.curves.push(curve);
+        self.controls.clear();
+        self.last = end;
+    }
+}
+
+#[inline]
+fn midpoint(a: Point, b: Point) -> Point {
+    point((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+impl Outline {
+    /// Returns the closed contours of the outline curves.
+    ///
+    /// Contours start at the first on-curve point of their first curve & are split
+    /// by the [`Self::contour_starts`] indices.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let outline = font.outline(font.glyph_id('o')).unwrap();
+    /// let contours = outline.contours();
+    ///
+    /// // outer & inner contours
+    /// assert_eq!(contours.len(), 2);
+    /// let on_curve_points = contours[0].points.iter().filter(|p| p.is_on_curve()).count();
+    /// assert!(on_curve_points > 1);
+    /// ```
+    pub fn contours(&self) -> Vec<Contour> {
+        let mut contours = Vec::new();
+        let mut points: Vec<ContourPoint> = Vec::new();
+        for curves in self.contour_curves() {
+            points.push(ContourPoint::on_curve(curves[0].start()));
+            for curve in curves {
+                match *curve {
+                    OutlineCurve::Line(..) => {}
+                    OutlineCurve::Quad(_, p1, _) => points.push(ContourPoint::quad_control(p1)),
+                    OutlineCurve::Cubic(_, p1, p2, _) => points.extend([
+                        ContourPoint::cubic_control(p1),
+                        ContourPoint::cubic_control(p2),
+                    ]),
+                }
+                points.push(ContourPoint::on_curve(curve.end()));
+            }
+            push_contour(&mut contours, &mut points);
+        }
+        contours
+    }
+
+    /// Returns an outline of the curves of `contours`, with bounds fitting the curve
+    /// control points.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let mut contours = font.outline(font.glyph_id('o')).unwrap().contours();
+    ///
+    /// // remove the inner contour to fill the 'o'
+    /// contours.truncate(1);
+    /// let filled = Outline::from_contours(&contours);
+    /// ```
+    pub fn from_contours<'a>(contours: impl IntoIterator<Item = &'a Contour>) -> Self {
+        let mut curves = Vec::new();
+        let mut contour_starts = Vec::new();
+        for contour in contours {
+            let start = curves.len();
+            curves.extend(contour.curves());
+            if curves.len() > start {
+                contour_starts.push(start);
+            }
+        }
+        Self {
+            bounds: curves_bounds(&curves),
+            curves,
+            contour_starts,
+        }
+    }
+}
+
+/// Moves `points` into a new contour, removing redundant points closing the contour.
+fn push_contour(contours: &mut Vec<Contour>, points: &mut Vec<ContourPoint>) {
+    while points.len() > 1
+        && points
+            .last()
+            .is_some_and(|p| p.is_on_curve() && p.point == points[0].point)
+    {
+        points.pop();
+    }
+    if !points.is_empty() {
+        contours.push(Contour {
+            points: core::mem::take(points),
+        });
+    }
+}
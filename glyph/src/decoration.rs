@@ -0,0 +1,172 @@
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{point, DecorationMetrics, Font, Glyph, Rect, ScaleFont};
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// Minimum glyph coverage considered ink by [`Decoration::with_skip_ink`].
+const INK_COVERAGE: f32 = 0.1;
+
+/// A horizontal text decoration line, e.g. an underline or strikeout, spanning a run of
+/// positioned glyphs in pixel coordinates.
+///
+/// The line is scaled & positioned relative to the baseline of the first glyph. It spans
+/// from the leftmost glyph position to the furthest glyph advance.
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+/// let scaled_font = font.as_scaled(24.0);
+/// let mut caret = point(10.0, 30.0);
+/// let glyphs: Vec<_> = "typography"
+///     .chars()
+///     .map(|c| {
+///         let glyph = scaled_font.scaled_glyph(c);
+///         let position = caret;
+///         caret.x += scaled_font.h_advance(glyph.id);
+///         Glyph { position, ..glyph }
+///     })
+///     .collect();
+///
+/// if let Some(underline) = Decoration::underline(&font, &glyphs) {
+///     // leave gaps around descenders
+///     let underline = underline.with_skip_ink(&font, &glyphs, 1.0);
+///     let bounds = underline.px_bounds();
+///     underline.draw(|x, y, c| {
+///         // draw pixel `(x, y)` relative to `bounds.min` with coverage: `c`
+///     });
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Decoration {
+    /// Exact line rectangle.
+    line: Rect,
+    px_bounds: Rect,
+    /// Pixel columns of `px_bounds` skipped around glyph ink.
+    skipped: Vec<bool>,
+}
+
+impl Decoration {
+    /// Returns a line at unscaled `metrics` spanning `glyphs`, or `None` if the
+    /// line would be empty.
+    pub fn new<F: Font>(font: F, glyphs: &[Glyph], metrics: DecorationMetrics) -> Option<Self> {
+        let first = glyphs.first()?;
+        let metrics = metrics.scaled(font.as_scaled(first.scale).v_scale_factor());
+
+        let (mut min_x, mut max_x) = (f32::INFINITY, f32::NEG_INFINITY);
+        for glyph in glyphs {
+            let advance = font.as_scaled(glyph.scale).h_advance(glyph.id);
+            min_x = min_x.min(glyph.position.x);
+            max_x = max_x.max(glyph.position.x + advance);
+        }
+        if max_x <= min_x || metrics.thickness <= 0.0 {
+            return None;
+        }
+
+        let top = first.position.y - metrics.position;
+        let line = Rect {
+            min: point(min_x, top),
+            max: point(max_x, top + metrics.thickness),
+        };
+        let px_bounds = Rect {
+            min: point(line.min.x.floor(), line.min.y.floor()),
+            max: point(line.max.x.ceil(), line.max.y.ceil()),
+        };
+        Some(Self {
+            line,
+            px_bounds,
+            skipped: vec![false; px_bounds.width() as usize],
+        })
+    }
+
+    /// Returns an underline spanning `glyphs` using [`Font::underline_metrics_unscaled`].
+    ///
+    /// Returns `None` if the font has no underline metrics or the line would be empty.
+    pub fn underline<F: Font>(font: F, glyphs: &[Glyph]) -> Option<Self> {
+        let metrics = font.underline_metrics_unscaled()?;
+        Self::new(font, glyphs, metrics)
+    }
+
+    /// Returns a strikeout line spanning `glyphs` using [`Font::strikeout_metrics_unscaled`].
+    ///
+    /// Returns `None` if the font has no strikeout metrics or the line would be empty.
+    pub fn strikeout<F: Font>(font: F, glyphs: &[Glyph]) -> Option<Self> {
+        let metrics = font.strikeout_metrics_unscaled()?;
+        Self::new(font, glyphs, metrics)
+    }
+
+    /// Returns the line with gaps where the outlines of `glyphs` cross it, e.g. descenders
+    /// crossing an underline. Gaps extend `padding` pixels around the glyph ink.
+    ///
+    /// Gaps are whole pixel columns of [`Self::px_bounds`].
+    pub fn with_skip_ink<F: Font>(mut self, font: F, glyphs: &[Glyph], padding: f32) -> Self {
+        let (min_y, max_y) = (self.line.min.y - padding, self.line.max.y + padding);
+        let bounds = self.px_bounds;
+        let skipped = &mut self.skipped;
+
+        for glyph in glyphs {
+            let Some(outlined) = font.outline_glyph(glyph.clone()) else {
+                continue;
+            };
+            let glyph_bounds = outlined.px_bounds();
+            if glyph_bounds.max.y <= min_y
+                || glyph_bounds.min.y >= max_y
+                || glyph_bounds.max.x + padding <= bounds.min.x
+                || glyph_bounds.min.x - padding >= bounds.max.x
+            {
+                continue;
+            }
+
+            outlined.draw(|x, y, c| {
+                let py = glyph_bounds.min.y + y as f32;
+                if c < INK_COVERAGE || py + 1.0 <= min_y || py >= max_y {
+                    return;
+                }
+                let px = glyph_bounds.min.x + x as f32;
+                let from = ((px - padding).floor() - bounds.min.x).max(0.0) as usize;
+                let to = ((px + 1.0 + padding).ceil() - bounds.min.x).max(0.0) as usize;
+                for skip in skipped.iter_mut().take(to).skip(from) {
+                    *skip = true;
+                }
+            });
+        }
+        self
+    }
+
+    /// Conservative whole pixel bounding box for the line.
+    #[inline]
+    pub fn px_bounds(&self) -> Rect {
+        self.px_bounds
+    }
+
+    /// Draw the line using a pixel & coverage handling function, like
+    /// [`OutlinedGlyph::draw`](crate::OutlinedGlyph::draw).
+    ///
+    /// The callback will be called for each `(x, y)` pixel coordinate inside the bounds
+    /// with a coverage value indicating how much the line covered that pixel.
+    /// Skipped pixels have `0.0` coverage.
+    pub fn draw<O: FnMut(u32, u32, f32)>(&self, mut o: O) {
+        let Rect { min, max } = self.px_bounds;
+        for y in 0..(max.y - min.y) as u32 {
+            let py = min.y + y as f32;
+            let coverage_y = overlap(py, self.line.min.y, self.line.max.y);
+            for (x, skipped) in self.skipped.iter().enumerate() {
+                let coverage = match skipped {
+                    true => 0.0,
+                    false => {
+                        let px = min.x + x as f32;
+                        coverage_y * overlap(px, self.line.min.x, self.line.max.x)
+                    }
+                };
+                o(x as u32, y, coverage);
+            }
+        }
+    }
+}
+
+/// Returns the length of the pixel `[p, p + 1]` within `[min, max]`.
+#[inline]
+fn overlap(p: f32, min: f32, max: f32) -> f32 {
+    ((p + 1.0).min(max) - p.max(min)).max(0.0)
+}
@@ -1,6 +1,8 @@
 use core::fmt;
 
 /// Invalid font data error.
+///
+/// See [`FontError`] for the detailed reason.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct InvalidFont;
 
@@ -12,3 +14,60 @@ impl fmt::Display for InvalidFont {
 
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidFont {}
+
+/// Detailed invalid font data error, see [`FontRef::parse`](crate::FontRef::parse).
+///
+/// Converts into [`InvalidFont`], e.g. using `?`.
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// let data = include_bytes!("../../dev/fonts/Exo2-Light.otf");
+///
+/// let err = FontRef::parse(&data[..100], 0).unwrap_err();
+/// assert_eq!(err, FontError::Malformed);
+///
+/// let err = FontRef::parse(data, 1).unwrap_err();
+/// assert_eq!(err, FontError::FaceIndexOutOfBounds { index: 1, face_count: 1 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FontError {
+    /// Font data is truncated or otherwise malformed, e.g. an incomplete download.
+    Malformed,
+    /// Font data does not start with a known font or font collection magic number,
+    /// e.g. unsupported WOFF compressed data.
+    UnknownMagic,
+    /// The face `index` is not less than the number of faces in the font,
+    /// `1` unless the data is a font collection.
+    FaceIndexOutOfBounds { index: u32, face_count: u32 },
+    /// A table required to use the font, identified by its `tag`, is missing or malformed.
+    RequiredTable { tag: [u8; 4] },
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => write!(f, "Malformed font data"),
+            Self::UnknownMagic => write!(f, "Unknown font format magic number"),
+            Self::FaceIndexOutOfBounds { index, face_count } => {
+                write!(f, "Face index {index} out of bounds of {face_count} faces")
+            }
+            Self::RequiredTable { tag } => write!(
+                f,
+                "Missing or malformed required table '{}'",
+                core::str::from_utf8(tag).unwrap_or("?")
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FontError {}
+
+impl From<FontError> for InvalidFont {
+    #[inline]
+    fn from(_: FontError) -> Self {
+        Self
+    }
+}
@@ -1,7 +1,14 @@
 use crate::{
-    point, v2, Glyph, GlyphId, GlyphSvg, Outline, OutlinedGlyph, PxScale, PxScaleFont, Rect,
-    ScaleFont,
+    metrics::select_line_metrics,
+    name::{select_language, ENGLISH_US},
+    point,
+    sink::sink_curves,
+    v2, DecorationMetrics, FontName, Glyph, GlyphId, GlyphSvg, LineMetrics, LineMetricsMode,
+    NameId, Os2Metrics, Outline, OutlineSink, OutlinedGlyph, PxScale, PxScaleFont, Rect, ScaleFont,
 };
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// Functionality required from font data.
 ///
@@ -100,6 +107,90 @@ pub trait Font {
     /// Scaling can be done with [`as_scaled`](Self::as_scaled).
     fn line_gap_unscaled(&self) -> f32;
 
+    /// Unscaled ascent, descent & line gap from the `mode` source.
+    /// See [glyph layout concepts](Font#glyph-layout-concepts).
+    ///
+    /// [`LineMetricsMode::Auto`] matches [`Font::ascent_unscaled`], [`Font::descent_unscaled`]
+    /// & [`Font::line_gap_unscaled`]. Other modes also fall back to these if not available.
+    ///
+    /// Scaling can be done with [`as_scaled`](Self::as_scaled).
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/OpenSans-Italic.ttf"))?;
+    ///
+    /// let typo = font.line_metrics_unscaled(LineMetricsMode::Typo);
+    /// assert_eq!(typo.ascent, 1567.0);
+    /// assert_eq!(typo.descent, -492.0);
+    /// assert_eq!(typo.line_gap, 132.0);
+    ///
+    /// let auto = font.line_metrics_unscaled(LineMetricsMode::Auto);
+    /// assert_eq!(auto.ascent, font.ascent_unscaled());
+    /// # Ok(()) }
+    /// ```
+    fn line_metrics_unscaled(&self, mode: LineMetricsMode) -> LineMetrics {
+        let auto = LineMetrics {
+            ascent: self.ascent_unscaled(),
+            descent: self.descent_unscaled(),
+            line_gap: self.line_gap_unscaled(),
+        };
+        select_line_metrics(mode, auto, auto, self.os2_metrics())
+    }
+
+    /// Returns OS/2 table values, e.g. typographic metrics, x-height & weight class.
+    ///
+    /// Returns `None` if not available, the default.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+    /// let os2 = font.os2_metrics().unwrap();
+    ///
+    /// assert_eq!(os2.x_height, Some(485.0));
+    /// assert_eq!(os2.weight_class, 300);
+    /// assert!(os2.fs_selection.use_typo_metrics());
+    /// # Ok(()) }
+    /// ```
+    fn os2_metrics(&self) -> Option<Os2Metrics> {
+        None
+    }
+
+    /// Unscaled underline position & thickness from the post table.
+    ///
+    /// Returns `None` if not available, the default.
+    ///
+    /// Scaling can be done with [`as_scaled`](Self::as_scaled).
+    /// See [`Decoration`](crate::Decoration) to draw lines.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+    /// let underline = font.underline_metrics_unscaled().unwrap();
+    ///
+    /// assert_eq!(underline.position, -75.0);
+    /// assert_eq!(underline.thickness, 50.0);
+    /// # Ok(()) }
+    /// ```
+    fn underline_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        None
+    }
+
+    /// Unscaled strikeout position & thickness from the OS/2 table.
+    ///
+    /// Returns `None` if not available, the default.
+    ///
+    /// Scaling can be done with [`as_scaled`](Self::as_scaled).
+    /// See [`Decoration`](crate::Decoration) to draw lines.
+    fn strikeout_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        None
+    }
+
     /// The slant angle of the font.
     ///
     /// Returns `0.0` if no angle or this info is not provided.
@@ -165,6 +256,100 @@ pub trait Font {
     /// Compute unscaled glyph outline curves & bounding box.
     fn outline(&self, id: GlyphId) -> Option<Outline>;
 
+    /// Sends unscaled glyph outline curves to `sink` & returns the unscaled bounding box,
+    /// or `None` if the glyph has no outline.
+    ///
+    /// Unlike [`Font::outline`] this does not allocate for [`FontRef`](crate::FontRef),
+    /// [`FontVec`](crate::FontVec) & [`FontArc`](crate::FontArc) wrapping either.
+    /// The default implementation sends the contours of [`Font::outline`].
+    ///
+    /// See [`OutlineSink`] for an example.
+    fn outline_into(&self, id: GlyphId, sink: &mut dyn OutlineSink) -> Option<Rect> {
+        let outline = self.outline(id)?;
+        sink_curves(&outline, sink);
+        Some(outline.bounds)
+    }
+
+    /// Returns all unicode name entries, e.g. localized family names.
+    ///
+    /// Returns empty, the default, for fonts without names.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+    ///
+    /// let family_languages: Vec<_> = font
+    ///     .names()
+    ///     .into_iter()
+    ///     .filter(|name| name.name_id == NameId::FAMILY)
+    ///     .map(|name| name.language_id)
+    ///     .collect();
+    /// assert!(family_languages.contains(&Some(0x0409)));
+    /// # Ok(()) }
+    /// ```
+    fn names(&self) -> Vec<FontName> {
+        Vec::new()
+    }
+
+    /// Returns the `name_id` name, preferring US English.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+    /// assert_eq!(font.name(NameId::FULL_NAME).as_deref(), Some("Exo 2 Light"));
+    /// assert_eq!(font.name(NameId::POST_SCRIPT_NAME).as_deref(), Some("Exo2-Light"));
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    fn name(&self, name_id: NameId) -> Option<String> {
+        self.localized_name(name_id, ENGLISH_US)
+    }
+
+    /// Returns the `name_id` name in the language of Windows language ID (LCID) `language_id`,
+    /// e.g. `0x0411` for Japanese.
+    ///
+    /// Falls back to another region of the same language, e.g. `0x0809` British English
+    /// for `0x0c09` Australian English, then US English, then any language.
+    ///
+    /// The default implementation selects from [`Font::names`].
+    fn localized_name(&self, name_id: NameId, language_id: u16) -> Option<String> {
+        let names = self.names();
+        let names = names.iter().filter(|name| name.name_id == name_id);
+        select_language(names, |name| name.language_id, language_id).map(|n| n.value.clone())
+    }
+
+    /// Returns the family name, e.g. `"Exo 2"`, preferring the typographic family name
+    /// over the legacy family name which may include a style, e.g. `"Exo 2 Light"`.
+    ///
+    /// Returns `None` if not defined.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+    /// assert_eq!(font.family_name().as_deref(), Some("Exo 2"));
+    /// assert_eq!(font.subfamily_name().as_deref(), Some("Light"));
+    /// # Ok(()) }
+    /// ```
+    fn family_name(&self) -> Option<String> {
+        self.name(NameId::TYPOGRAPHIC_FAMILY)
+            .or_else(|| self.name(NameId::FAMILY))
+    }
+
+    /// Returns the subfamily, or style, name within the [`Font::family_name`] family,
+    /// e.g. `"Light"` or `"Bold Italic"`, preferring the typographic subfamily name.
+    ///
+    /// Returns `None` if not defined.
+    fn subfamily_name(&self) -> Option<String> {
+        self.name(NameId::TYPOGRAPHIC_SUBFAMILY)
+            .or_else(|| self.name(NameId::SUBFAMILY))
+    }
+
     /// The number of glyphs present in this font. Glyph identifiers for this
     /// font will always be in the range `0..self.glyph_count()`
     fn glyph_count(&self) -> usize;
@@ -343,6 +528,26 @@ impl<F: Font> Font for &F {
         (*self).line_gap_unscaled()
     }
 
+    #[inline]
+    fn line_metrics_unscaled(&self, mode: LineMetricsMode) -> LineMetrics {
+        (*self).line_metrics_unscaled(mode)
+    }
+
+    #[inline]
+    fn os2_metrics(&self) -> Option<Os2Metrics> {
+        (*self).os2_metrics()
+    }
+
+    #[inline]
+    fn underline_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        (*self).underline_metrics_unscaled()
+    }
+
+    #[inline]
+    fn strikeout_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        (*self).strikeout_metrics_unscaled()
+    }
+
     #[inline]
     fn italic_angle(&self) -> f32 {
         (*self).italic_angle()
@@ -383,6 +588,36 @@ impl<F: Font> Font for &F {
         (*self).outline(glyph)
     }
 
+    #[inline]
+    fn outline_into(&self, glyph: GlyphId, sink: &mut dyn OutlineSink) -> Option<Rect> {
+        (*self).outline_into(glyph, sink)
+    }
+
+    #[inline]
+    fn names(&self) -> Vec<FontName> {
+        (*self).names()
+    }
+
+    #[inline]
+    fn name(&self, name_id: NameId) -> Option<String> {
+        (*self).name(name_id)
+    }
+
+    #[inline]
+    fn localized_name(&self, name_id: NameId, language_id: u16) -> Option<String> {
+        (*self).localized_name(name_id, language_id)
+    }
+
+    #[inline]
+    fn family_name(&self) -> Option<String> {
+        (*self).family_name()
+    }
+
+    #[inline]
+    fn subfamily_name(&self) -> Option<String> {
+        (*self).subfamily_name()
+    }
+
     #[inline]
     fn glyph_count(&self) -> usize {
         (*self).glyph_count()
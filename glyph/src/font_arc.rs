@@ -1,4 +1,10 @@
-use crate::{v2, Font, FontRef, FontVec, GlyphId, InvalidFont, Outline};
+use crate::{
+    ttfp::SharedFont, v2, DecorationMetrics, Font, FontError, FontName, FontRef, FontVec, GlyphId,
+    InvalidFont, LineMetrics, LineMetricsMode, NameId, Os2Metrics, Outline, OutlineSink, Rect,
+};
+#[cfg(feature = "variable-fonts")]
+use crate::{NamedInstance, VariableFont, VariationAxis};
+use alloc::string::String;
 use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
@@ -18,10 +24,47 @@ use core::fmt;
 /// assert_eq!(font.glyph_id('s'), ab_glyph::GlyphId(56));
 /// # Ok(()) }
 /// ```
+///
+/// # Variable fonts
+/// Fonts created from data, e.g. with [`FontArc::try_from_vec`], [`FontArc::try_from_data`]
+/// or [`FontCollection`](crate::FontCollection), or converted with `From<FontVec>` or
+/// `From<FontRef<'static>>`, implement [`VariableFont`](crate::VariableFont), keeping
+/// variations already set. Setting a variation replaces this `FontArc` with a new instance
+/// sharing the font data, without affecting clones. So multiple instances of a variable font
+/// may be used concurrently.
+///
+/// ```
+/// use ab_glyph::{Font, FontArc, VariableFont};
+///
+/// # fn main() -> Result<(), ab_glyph::InvalidFont> {
+/// let regular = FontArc::try_from_slice(include_bytes!("../../dev/fonts/Cantarell-VF.otf"))?;
+///
+/// let mut bold = regular.clone();
+/// assert!(bold.set_variation(b"wght", 700.0));
+///
+/// let o = regular.glyph_id('o');
+/// assert!(bold.h_advance_unscaled(o) > regular.h_advance_unscaled(o));
+/// # Ok(()) }
+/// ```
+///
+/// Fonts wrapped with [`FontArc::new`], e.g. a [`SyntheticFont`](crate::SyntheticFont),
+/// cannot be varied.
+#[derive(Clone)]
+pub struct FontArc(ArcFont);
+
+/// Font stored by a [`FontArc`].
 #[derive(Clone)]
-pub struct FontArc(Arc<dyn Font + Send + Sync + 'static>);
+enum ArcFont {
+    /// Font parsed from data, supporting variations.
+    Shared(Arc<SharedFont>),
+    /// Any other font.
+    Dyn(Arc<dyn Font + Send + Sync + 'static>),
+}
 
 impl FontArc {
+    /// Wraps any font, which will not support variations.
+    /// Use `FontArc::from` to keep a [`FontVec`] or [`FontRef`] variable.
+    ///
     /// # Example
     /// ```
     /// # use ab_glyph::*;
@@ -33,7 +76,22 @@ impl FontArc {
     /// ```
     #[inline]
     pub fn new<F: Font + Send + Sync + 'static>(font: F) -> Self {
-        Self(Arc::new(font))
+        Self(ArcFont::Dyn(Arc::new(font)))
+    }
+
+    /// Wraps a font parsed from shared data, supporting variations.
+    #[inline]
+    pub(crate) fn from_shared(font: SharedFont) -> Self {
+        Self(ArcFont::Shared(Arc::new(font)))
+    }
+
+    /// Returns the wrapped font.
+    #[inline]
+    fn font(&self) -> &(dyn Font + Send + Sync + 'static) {
+        match &self.0 {
+            ArcFont::Shared(font) => &**font,
+            ArcFont::Dyn(font) => &**font,
+        }
     }
 
     /// Creates an `FontArc` from owned data.
@@ -64,6 +122,56 @@ impl FontArc {
     pub fn try_from_slice(data: &'static [u8]) -> Result<Self, InvalidFont> {
         Ok(FontRef::try_from_slice(data)?.into())
     }
+
+    /// Creates an `FontArc` referencing shared owned data without copying,
+    /// e.g. `Arc<[u8]>`, `bytes::Bytes` or memory-mapped font files.
+    ///
+    /// Cloning cheaply cloneable data, like `Arc<[u8]>`, allows multiple fonts
+    /// to share a single allocation.
+    ///
+    /// For font collections see [`FontArc::try_from_data_and_index`]
+    /// or [`FontCollection`](crate::FontCollection).
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), FontError> {
+    /// # let owned_font_data = include_bytes!("../../dev/fonts/Exo2-Light.otf").to_vec();
+    /// let data: Arc<[u8]> = owned_font_data.into();
+    /// let font = FontArc::try_from_data(Arc::clone(&data))?;
+    /// let font2 = FontArc::try_from_data(data)?;
+    /// assert_eq!(font.font_data().as_ptr(), font2.font_data().as_ptr());
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn try_from_data<D>(data: D) -> Result<Self, FontError>
+    where
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Self::try_from_data_and_index(data, 0)
+    }
+
+    /// Creates an `FontArc` referencing face `index` of shared owned data without copying,
+    /// see [`FontArc::try_from_data`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), FontError> {
+    /// # let owned_font_data = include_bytes!("../../dev/fonts/Exo2-Light.otf").to_vec();
+    /// let data: Arc<[u8]> = owned_font_data.into();
+    /// let font = FontArc::try_from_data_and_index(data, 0)?;
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn try_from_data_and_index<D>(data: D, index: u32) -> Result<Self, FontError>
+    where
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Ok(Self::from_shared(SharedFont::parse(Arc::new(data), index)?))
+    }
 }
 
 impl fmt::Debug for FontArc {
@@ -75,105 +183,200 @@ impl fmt::Debug for FontArc {
 impl Font for FontArc {
     #[inline]
     fn units_per_em(&self) -> Option<f32> {
-        self.0.units_per_em()
+        self.font().units_per_em()
     }
 
     #[inline]
     fn ascent_unscaled(&self) -> f32 {
-        self.0.ascent_unscaled()
+        self.font().ascent_unscaled()
     }
 
     #[inline]
     fn descent_unscaled(&self) -> f32 {
-        self.0.descent_unscaled()
+        self.font().descent_unscaled()
     }
 
     #[inline]
     fn line_gap_unscaled(&self) -> f32 {
-        self.0.line_gap_unscaled()
+        self.font().line_gap_unscaled()
+    }
+
+    #[inline]
+    fn line_metrics_unscaled(&self, mode: LineMetricsMode) -> LineMetrics {
+        self.font().line_metrics_unscaled(mode)
+    }
+
+    #[inline]
+    fn os2_metrics(&self) -> Option<Os2Metrics> {
+        self.font().os2_metrics()
+    }
+
+    #[inline]
+    fn underline_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        self.font().underline_metrics_unscaled()
+    }
+
+    #[inline]
+    fn strikeout_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        self.font().strikeout_metrics_unscaled()
     }
 
     #[inline]
     fn italic_angle(&self) -> f32 {
-        self.0.italic_angle()
+        self.font().italic_angle()
     }
 
     #[inline]
     fn glyph_id(&self, c: char) -> GlyphId {
-        self.0.glyph_id(c)
+        self.font().glyph_id(c)
     }
 
     #[inline]
     fn h_advance_unscaled(&self, id: GlyphId) -> f32 {
-        self.0.h_advance_unscaled(id)
+        self.font().h_advance_unscaled(id)
     }
 
     #[inline]
     fn h_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
-        self.0.h_side_bearing_unscaled(id)
+        self.font().h_side_bearing_unscaled(id)
     }
 
     #[inline]
     fn v_advance_unscaled(&self, id: GlyphId) -> f32 {
-        self.0.v_advance_unscaled(id)
+        self.font().v_advance_unscaled(id)
     }
 
     #[inline]
     fn v_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
-        self.0.v_side_bearing_unscaled(id)
+        self.font().v_side_bearing_unscaled(id)
     }
 
     #[inline]
     fn kern_unscaled(&self, first: GlyphId, second: GlyphId) -> f32 {
-        self.0.kern_unscaled(first, second)
+        self.font().kern_unscaled(first, second)
     }
 
     #[inline]
     fn outline(&self, glyph: GlyphId) -> Option<Outline> {
-        self.0.outline(glyph)
+        self.font().outline(glyph)
+    }
+
+    #[inline]
+    fn outline_into(&self, glyph: GlyphId, sink: &mut dyn OutlineSink) -> Option<Rect> {
+        self.font().outline_into(glyph, sink)
+    }
+
+    #[inline]
+    fn names(&self) -> Vec<FontName> {
+        self.font().names()
+    }
+
+    #[inline]
+    fn name(&self, name_id: NameId) -> Option<String> {
+        self.font().name(name_id)
+    }
+
+    #[inline]
+    fn localized_name(&self, name_id: NameId, language_id: u16) -> Option<String> {
+        self.font().localized_name(name_id, language_id)
+    }
+
+    #[inline]
+    fn family_name(&self) -> Option<String> {
+        self.font().family_name()
+    }
+
+    #[inline]
+    fn subfamily_name(&self) -> Option<String> {
+        self.font().subfamily_name()
     }
 
     #[inline]
     fn glyph_count(&self) -> usize {
-        self.0.glyph_count()
+        self.font().glyph_count()
     }
 
     #[inline]
     fn codepoint_ids(&self) -> crate::CodepointIdIter<'_> {
-        self.0.codepoint_ids()
+        self.font().codepoint_ids()
     }
 
     #[inline]
     fn glyph_raster_image2(&self, id: GlyphId, size: u16) -> Option<v2::GlyphImage<'_>> {
-        self.0.glyph_raster_image2(id, size)
+        self.font().glyph_raster_image2(id, size)
     }
 
     #[inline]
     fn glyph_svg_image(&self, id: GlyphId) -> Option<crate::GlyphSvg<'_>> {
-        self.0.glyph_svg_image(id)
+        self.font().glyph_svg_image(id)
     }
 
     #[inline]
     fn font_data(&self) -> &[u8] {
-        self.0.font_data()
+        self.font().font_data()
     }
 }
 
 impl From<FontVec> for FontArc {
     #[inline]
     fn from(font: FontVec) -> Self {
-        Self::new(font)
+        Self::from_shared(font.into())
     }
 }
 impl From<FontRef<'static>> for FontArc {
     #[inline]
     fn from(font: FontRef<'static>) -> Self {
-        Self::new(font)
+        Self::from_shared(font.into())
     }
 }
 impl From<Arc<dyn Font + Send + Sync + 'static>> for FontArc {
     #[inline]
     fn from(font: Arc<dyn Font + Send + Sync + 'static>) -> Self {
-        Self(font)
+        Self(ArcFont::Dyn(font))
+    }
+}
+
+#[cfg(feature = "variable-fonts")]
+impl VariableFont for FontArc {
+    /// Sets a variation axis coordinate value by it's tag, replacing this font with
+    /// a new instance sharing the font data.
+    ///
+    /// Returns false if there is no such axis tag, or the font cannot be varied.
+    fn set_variation(&mut self, tag: &[u8; 4], value: f32) -> bool {
+        self.vary(|font| font.set_variation(tag, value))
+    }
+
+    fn variations(&self) -> Vec<VariationAxis> {
+        match &self.0 {
+            ArcFont::Shared(font) => font.variations(),
+            ArcFont::Dyn(_) => Vec::new(),
+        }
+    }
+
+    fn named_instances(&self) -> Vec<NamedInstance> {
+        match &self.0 {
+            ArcFont::Shared(font) => font.named_instances(),
+            ArcFont::Dyn(_) => Vec::new(),
+        }
+    }
+
+    fn set_named_instance(&mut self, index: usize) -> bool {
+        self.vary(|font| font.set_named_instance(index))
+    }
+}
+
+#[cfg(feature = "variable-fonts")]
+impl FontArc {
+    /// Replaces this font with a clone modified by `vary`, if it returns true.
+    fn vary(&mut self, vary: impl FnOnce(&mut SharedFont) -> bool) -> bool {
+        let ArcFont::Shared(font) = &self.0 else {
+            return false;
+        };
+        let mut font = SharedFont::clone(font);
+        let varied = vary(&mut font);
+        if varied {
+            *self = Self::from_shared(font);
+        }
+        varied
     }
 }
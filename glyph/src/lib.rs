@@ -24,31 +24,55 @@
 extern crate alloc;
 
 mod codepoint_ids;
+#[cfg(target_has_atomic = "ptr")]
+mod collection;
+mod contour;
+mod decoration;
 mod err;
 mod font;
 #[cfg(target_has_atomic = "ptr")]
 mod font_arc;
 mod glyph;
+mod metrics;
+mod msdf;
+mod name;
 #[cfg(all(feature = "libm", not(feature = "std")))]
 mod nostd_float;
 mod outlined;
 mod scale;
+mod sdf;
+mod sink;
+mod stroke;
+mod svg;
+mod synthetic;
+mod transform;
 mod ttfp;
 #[cfg(feature = "variable-fonts")]
 mod variable;
 
-#[cfg(target_has_atomic = "ptr")]
-pub use crate::font_arc::*;
 #[allow(deprecated)]
 pub use crate::{
     codepoint_ids::*,
+    contour::*,
+    decoration::*,
     err::*,
     font::*,
     glyph::*,
+    metrics::*,
+    name::*,
     outlined::*,
     scale::*,
+    sink::*,
+    stroke::*,
+    svg::*,
+    synthetic::*,
+    transform::*,
     ttfp::{FontRef, FontVec},
 };
-pub use ab_glyph_rasterizer::{point, Point};
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::{collection::*, font_arc::*};
+pub use ab_glyph_rasterizer::{
+    point, CoveragePixel, FillRule, LcdFilter, Point, Rasterizer, SubpixelLayout,
+};
 #[cfg(feature = "variable-fonts")]
 pub use variable::*;
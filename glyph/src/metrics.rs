@@ -0,0 +1,195 @@
+/// Font-wide values from the OS/2 table, unscaled in font units.
+///
+/// See [`Font::os2_metrics`](crate::Font::os2_metrics).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Os2Metrics {
+    /// Typographic ascent, `sTypoAscender`.
+    pub typo_ascent: f32,
+    /// Typographic descent, `sTypoDescender`, usually negative.
+    pub typo_descent: f32,
+    /// Typographic line gap, `sTypoLineGap`.
+    pub typo_line_gap: f32,
+    /// Windows clipping ascent, `usWinAscent`.
+    pub win_ascent: f32,
+    /// Windows clipping descent, negative below the baseline, i.e. `-usWinDescent`.
+    pub win_descent: f32,
+    /// Height of lowercase letters, like 'x', above the baseline. `None` before OS/2 version 2.
+    pub x_height: Option<f32>,
+    /// Height of uppercase letters above the baseline. `None` before OS/2 version 2.
+    pub cap_height: Option<f32>,
+    /// Weight class, e.g. `400` regular or `700` bold.
+    pub weight_class: u16,
+    /// Width class, from `1` ultra-condensed to `9` ultra-expanded, `5` is normal.
+    pub width_class: u16,
+    /// Font style flags, e.g. italic, bold or whether to use typographic metrics.
+    pub fs_selection: FsSelection,
+}
+
+/// OS/2 `fsSelection` font style flags.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FsSelection(pub u16);
+
+impl FsSelection {
+    /// Bit 0: glyphs are italic.
+    pub const ITALIC: u16 = 1 << 0;
+    /// Bit 1: glyphs are underscored.
+    pub const UNDERSCORE: u16 = 1 << 1;
+    /// Bit 2: glyphs have their foreground & background reversed.
+    pub const NEGATIVE: u16 = 1 << 2;
+    /// Bit 3: glyphs are outlined, i.e. hollow.
+    pub const OUTLINED: u16 = 1 << 3;
+    /// Bit 4: glyphs are overstruck.
+    pub const STRIKEOUT: u16 = 1 << 4;
+    /// Bit 5: glyphs are emboldened.
+    pub const BOLD: u16 = 1 << 5;
+    /// Bit 6: glyphs are in the standard weight & style, without other style bits set.
+    pub const REGULAR: u16 = 1 << 6;
+    /// Bit 7: typographic metrics should be used for line metrics,
+    /// see [`LineMetricsMode::Auto`].
+    pub const USE_TYPO_METRICS: u16 = 1 << 7;
+    /// Bit 8: the font name table is consistent with a weight, width & slope family.
+    pub const WWS: u16 = 1 << 8;
+    /// Bit 9: glyphs are oblique, i.e. slanted rather than a designed italic.
+    pub const OBLIQUE: u16 = 1 << 9;
+
+    /// Returns `true` if all bits of `flags` are set.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// let fs_selection = FsSelection(FsSelection::BOLD | FsSelection::ITALIC);
+    /// assert!(fs_selection.contains(FsSelection::BOLD));
+    /// assert!(!fs_selection.contains(FsSelection::BOLD | FsSelection::OBLIQUE));
+    /// ```
+    #[inline]
+    pub fn contains(self, flags: u16) -> bool {
+        self.0 & flags == flags
+    }
+
+    #[inline]
+    pub fn is_italic(self) -> bool {
+        self.contains(Self::ITALIC)
+    }
+
+    #[inline]
+    pub fn is_bold(self) -> bool {
+        self.contains(Self::BOLD)
+    }
+
+    #[inline]
+    pub fn is_regular(self) -> bool {
+        self.contains(Self::REGULAR)
+    }
+
+    #[inline]
+    pub fn is_oblique(self) -> bool {
+        self.contains(Self::OBLIQUE)
+    }
+
+    #[inline]
+    pub fn use_typo_metrics(self) -> bool {
+        self.contains(Self::USE_TYPO_METRICS)
+    }
+}
+
+/// Source of font [`LineMetrics`].
+///
+/// Platforms differ in the metrics they use to lay out lines, so a font may
+/// be spaced differently depending on the mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineMetricsMode {
+    /// Typographic OS/2 metrics if the font sets [`FsSelection::USE_TYPO_METRICS`],
+    /// otherwise hhea metrics. Matches [`Font::ascent_unscaled`](crate::Font::ascent_unscaled),
+    /// [`Font::descent_unscaled`](crate::Font::descent_unscaled) &
+    /// [`Font::line_gap_unscaled`](crate::Font::line_gap_unscaled).
+    #[default]
+    Auto,
+    /// Horizontal header (hhea) metrics, as used by macOS.
+    Hhea,
+    /// Typographic OS/2 metrics.
+    Typo,
+    /// Windows OS/2 clipping metrics, as used by GDI. The line gap is the hhea line gap
+    /// in excess of the windows height.
+    Win,
+}
+
+/// Ascent, descent & line gap used to lay out lines of text.
+/// See [glyph layout concepts](crate::Font#glyph-layout-concepts).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LineMetrics {
+    pub ascent: f32,
+    /// Descent, usually negative.
+    pub descent: f32,
+    pub line_gap: f32,
+}
+
+impl LineMetrics {
+    /// Height `ascent - descent`.
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.ascent - self.descent
+    }
+
+    /// Distance between consecutive baselines `ascent - descent + line_gap`.
+    #[inline]
+    pub fn line_height(&self) -> f32 {
+        self.height() + self.line_gap
+    }
+
+    /// Returns metrics multiplied by `scale`.
+    #[inline]
+    pub(crate) fn scaled(self, scale: f32) -> Self {
+        Self {
+            ascent: self.ascent * scale,
+            descent: self.descent * scale,
+            line_gap: self.line_gap * scale,
+        }
+    }
+}
+
+/// Position & thickness of a text decoration line, e.g. an underline.
+///
+/// See [`Font::underline_metrics_unscaled`](crate::Font::underline_metrics_unscaled).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DecorationMetrics {
+    /// Offset of the top of the line above the baseline, negative below the baseline.
+    pub position: f32,
+    pub thickness: f32,
+}
+
+impl DecorationMetrics {
+    /// Returns metrics multiplied by `scale`.
+    #[inline]
+    pub(crate) fn scaled(self, scale: f32) -> Self {
+        Self {
+            position: self.position * scale,
+            thickness: self.thickness * scale,
+        }
+    }
+}
+
+/// Returns `mode` line metrics, falling back to `auto` metrics without OS/2 metrics.
+pub(crate) fn select_line_metrics(
+    mode: LineMetricsMode,
+    auto: LineMetrics,
+    hhea: LineMetrics,
+    os2: Option<Os2Metrics>,
+) -> LineMetrics {
+    match (mode, os2) {
+        (LineMetricsMode::Hhea, _) => hhea,
+        (LineMetricsMode::Typo, Some(os2)) => LineMetrics {
+            ascent: os2.typo_ascent,
+            descent: os2.typo_descent,
+            line_gap: os2.typo_line_gap,
+        },
+        (LineMetricsMode::Win, Some(os2)) => {
+            let win_height = os2.win_ascent - os2.win_descent;
+            LineMetrics {
+                ascent: os2.win_ascent,
+                descent: os2.win_descent,
+                line_gap: (hhea.line_height() - win_height).max(0.0),
+            }
+        }
+        _ => auto,
+    }
+}
@@ -0,0 +1,345 @@
+//! Multi-channel signed distance field generation with edge coloring.
+use crate::{
+    point,
+    sdf::{cross, distance_value, dot, length, Edge, EdgeShape},
+    CoveragePixel, OutlinedGlyph, Point,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Sine of the min angle between edge directions considered a corner, 3 radians.
+const CORNER_SIN_THRESHOLD: f32 = 0.141_12;
+/// Distances within this many pixels are considered equal when selecting the closest edge.
+const DISTANCE_EPSILON: f32 = 1e-4;
+
+/// Edge color channel bit flags.
+const RED: u8 = 1;
+const GREEN: u8 = 2;
+const BLUE: u8 = 4;
+const MAGENTA: u8 = RED | BLUE;
+const CYAN: u8 = GREEN | BLUE;
+const WHITE: u8 = RED | GREEN | BLUE;
+
+/// Returns `true` if edge directions `a` then `b` meet at a corner.
+fn is_corner(a: Point, b: Point) -> bool {
+    let (a_len, b_len) = (length(a), length(b));
+    if a_len == 0.0 || b_len == 0.0 {
+        return false;
+    }
+    dot(a, b) <= 0.0 || (cross(a, b) / (a_len * b_len)).abs() > CORNER_SIN_THRESHOLD
+}
+
+/// Returns the next color in the cycle cyan -> magenta -> yellow, avoiding `banned`.
+fn switch_color(color: u8, banned: u8) -> u8 {
+    let combined = color & banned;
+    if matches!(combined, RED | GREEN | BLUE) {
+        return combined ^ WHITE;
+    }
+    ((color << 1) | (color >> 2)) & WHITE
+}
+
+/// Returns `0`, `1` or `2` splitting `position` of `n` into 3 symmetrical parts.
+fn symmetrical_trichotomy(position: usize, n: usize) -> usize {
+    (2.0 + 2.875 * position as f32 / (n - 1) as f32 - 1.4375 + 0.5) as usize - 1
+}
+
+/// Assigns colors to the edges of a closed contour so that edges meeting at a corner
+/// share exactly one channel, allowing the median of the channels to keep the corner sharp.
+fn color_contour(contour: &[Edge], colored: &mut Vec<(Edge, u8)>) {
+    let corners: Vec<usize> = (0..contour.len())
+        .filter(|&i| {
+            let prev = contour[(i + contour.len() - 1) % contour.len()];
+            is_corner(prev.direction(1.0), contour[i].direction(0.0))
+        })
+        .collect();
+
+    match corners[..] {
+        [] => colored.extend(contour.iter().map(|e| (*e, WHITE))),
+        // teardrop, split the contour into 3 colored parts
+        [corner] => {
+            let colors = [CYAN, WHITE, MAGENTA];
+            let m = contour.len();
+            let edge = |i: usize| contour[(corner + i) % m];
+            if m >= 3 {
+                colored.extend((0..m).map(|i| (edge(i), colors[symmetrical_trichotomy(i, m)])));
+            } else if m == 2 {
+                let [a0, a1, a2] = edge(0).split_in_thirds();
+                let [b0, b1, b2] = edge(1).split_in_thirds();
+                colored.extend([
+                    (a0, colors[0]),
+                    (a1, colors[0]),
+                    (a2, colors[1]),
+                    (b0, colors[1]),
+                    (b1, colors[2]),
+                    (b2, colors[2]),
+                ]);
+            } else {
+                let [a0, a1, a2] = edge(0).split_in_thirds();
+                colored.extend([(a0, colors[0]), (a1, colors[1]), (a2, colors[2])]);
+            }
+        }
+        _ => {
+            let m = contour.len();
+            let start = corners[0];
+            let initial = CYAN;
+            let mut color = initial;
+            let mut spline = 0;
+            for i in 0..m {
+                let index = (start + i) % m;
+                if corners.get(spline + 1) == Some(&index) {
+                    spline += 1;
+                    let banned = if spline == corners.len() - 1 {
+                        initial
+                    } else {
+                        0
+                    };
+                    color = switch_color(color, banned);
+                }
+                colored.push((contour[index], color));
+            }
+        }
+    }
+}
+
+/// Closest edge candidate for a single channel.
+#[derive(Debug, Clone, Copy)]
+struct Closest {
+    distance: f32,
+    /// Alignment of the edge direction with the direction to the point, lower is
+    /// more perpendicular & preferred between equally distant edges.
+    alignment: f32,
+    edge: Edge,
+    t: f32,
+}
+
+impl Closest {
+    /// Returns `true` if `self` is closer than `other`.
+    fn is_closer(&self, other: Option<&Self>) -> bool {
+        let Some(other) = other else { return true };
+        if (self.distance - other.distance).abs() <= DISTANCE_EPSILON {
+            self.alignment < other.alignment
+        } else {
+            self.distance < other.distance
+        }
+    }
+
+    /// Returns the signed pseudo-distance from `p` to the edge, positive on the left of
+    /// the edge direction. Beyond the edge endpoints this is the distance to the
+    /// endpoint tangent lines, which extends edges past corners keeping them sharp.
+    fn pseudo_distance(&self, p: Point) -> f32 {
+        let edge = &self.edge;
+        if self.t == 0.0 || self.t == 1.0 {
+            let (q, dir) = if self.t == 0.0 {
+                (edge.start(), edge.direction(0.0))
+            } else {
+                (edge.end(), edge.direction(1.0))
+            };
+            let along = dot(p - q, dir);
+            let beyond = if self.t == 0.0 {
+                along < 0.0
+            } else {
+                along > 0.0
+            };
+            let dir_len = length(dir);
+            if beyond && dir_len > 0.0 {
+                let pseudo = cross(dir, p - q) / dir_len;
+                if pseudo.abs() <= self.distance {
+                    return pseudo;
+                }
+            }
+        }
+        let side = cross(edge.direction(self.t), p - edge.point(self.t));
+        if side < 0.0 {
+            -self.distance
+        } else {
+            self.distance
+        }
+    }
+}
+
+/// Colored outline edges in pixel coordinates supporting multi-channel distance queries.
+#[derive(Debug)]
+struct ColoredShape {
+    shape: EdgeShape,
+    colored: Vec<(Edge, u8)>,
+    /// `1.0` or `-1.0` so that distances are positive inside the outline.
+    orientation: f32,
+}
+
+impl ColoredShape {
+    /// Returns the colored `shape` of consecutive contours of `contour_lens` edges.
+    fn new(shape: EdgeShape, contour_lens: impl Iterator<Item = usize>) -> Self {
+        let mut colored = Vec::with_capacity(shape.edges.len());
+        let mut contour = Vec::new();
+        let mut edges = &shape.edges[..];
+        for len in contour_lens {
+            let (contour_edges, rest) = edges.split_at(len);
+            edges = rest;
+            contour.clear();
+            contour.extend(contour_edges.iter().filter(|e| e.start() != e.end()));
+            if !contour.is_empty() {
+                color_contour(&contour, &mut colored);
+            }
+        }
+
+        let orientation = if shape.area() < 0.0 { -1.0 } else { 1.0 };
+        Self {
+            shape,
+            colored,
+            orientation,
+        }
+    }
+
+    /// Returns `[r, g, b, sdf]` distances from `p`, positive inside, clamped to
+    /// `-max..=max`. The median of `r, g, b` has the same sign as the true signed
+    /// distance `sdf`.
+    fn distances(&self, p: Point, max: f32) -> [f32; 4] {
+        let mut closest: [Option<Closest>; 3] = [None; 3];
+        for (edge, color) in &self.colored {
+            let bounds_distance = edge.bounds_distance(p);
+            let skip = (0..3).all(|c| {
+                color & (1 << c) == 0
+                    || closest[c].is_some_and(|cl| bounds_distance > cl.distance + DISTANCE_EPSILON)
+            });
+            if skip {
+                continue;
+            }
+
+            let (distance, t) = edge.distance(p);
+            let alignment = if t == 0.0 || t == 1.0 {
+                let (dir, to_p) = (edge.direction(t), p - edge.point(t));
+                let len = length(dir) * length(to_p);
+                if len > 0.0 {
+                    (dot(dir, to_p) / len).abs()
+                } else {
+                    0.0
+                }
+            } else {
+                0.0
+            };
+            let candidate = Closest {
+                distance,
+                alignment,
+                edge: *edge,
+                t,
+            };
+            for (c, closest) in closest.iter_mut().enumerate() {
+                if color & (1 << c) != 0 && candidate.is_closer(closest.as_ref()) {
+                    *closest = Some(candidate);
+                }
+            }
+        }
+
+        let sdf = self.shape.signed_distance(p, max);
+        let [r, g, b] = closest.map(|c| match c {
+            Some(c) => (self.orientation * c.pseudo_distance(p)).clamp(-max, max),
+            None => sdf,
+        });
+        if (median(r, g, b) > 0.0) != (sdf > 0.0) {
+            // fix sign errors, e.g. where contours overlap
+            return [sdf; 4];
+        }
+        [r, g, b, sdf]
+    }
+}
+
+#[inline]
+fn median(a: f32, b: f32, c: f32) -> f32 {
+    a.min(b).max(a.max(b).min(c))
+}
+
+impl OutlinedGlyph {
+    /// Draw a multi-channel signed distance field of this glyph outline using a pixel &
+    /// distance handling function.
+    ///
+    /// Outline contours are split into red, green & blue colored edges so that the
+    /// median of the 3 channel distances reproduces sharp corners, unlike a single
+    /// channel [`Self::draw_sdf`] which rounds them off when magnified.
+    ///
+    /// The callback will be called for each `(x, y)` pixel coordinate inside the
+    /// [`Self::sdf_px_bounds`] with `[r, g, b, a]` distances in pixels,
+    /// positive inside & negative outside the glyph, clamped to `-spread..=spread`,
+    /// so are signed zeros for a zero or negative `spread`. The `a` channel is the
+    /// true signed distance, as drawn by [`Self::draw_sdf`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('W').with_scale(24.0);
+    /// let w = font.outline_glyph(glyph).unwrap();
+    /// w.draw_msdf(4.0, |x, y, [r, g, b, a]| {
+    ///     // e.g. inside if median(r, g, b) > 0.0
+    /// });
+    /// ```
+    pub fn draw_msdf<O: FnMut(u32, u32, [f32; 4])>(&self, spread: f32, mut o: O) {
+        let bounds = self.sdf_px_bounds(spread);
+        let (w, h) = (bounds.width() as u32, bounds.height() as u32);
+        let shape = ColoredShape::new(
+            self.px_edge_shape(self.px_bounds().min - bounds.min),
+            self.outline.contour_curves().map(<[_]>::len),
+        );
+        let max = spread.max(0.0);
+
+        for y in 0..h {
+            for x in 0..w {
+                let center = point(x as f32 + 0.5, y as f32 + 0.5);
+                o(x, y, shape.distances(center, max));
+            }
+        }
+    }
+
+    /// Draw a multi-channel signed distance field of this glyph outline into a `target`
+    /// pixel buffer of 3 `[r, g, b]` or 4 `[r, g, b, a]` channel pixels, writing each row
+    /// `y` of the [`Self::sdf_px_bounds`] sized image to
+    /// `target[offset + y * stride..][..width]`.
+    ///
+    /// Channel distances are mapped to `0.0..=1.0` values as with [`Self::draw_sdf_into`],
+    /// see [`Self::draw_msdf`]. A shader should threshold the median of the `r, g, b`
+    /// channels at `0.5`.
+    ///
+    /// # Panics
+    /// Panics if pixels do not have 3 or 4 channels, `stride` is less than the width or
+    /// `target` is too small to hold all rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('W').with_scale(24.0);
+    /// let w = font.outline_glyph(glyph).unwrap();
+    /// let bounds = w.sdf_px_bounds(4.0);
+    /// let (width, height) = (bounds.width() as usize, bounds.height() as usize);
+    ///
+    /// let mut msdf = vec![[0u8; 3]; width * height];
+    /// w.draw_msdf_into(4.0, &mut msdf, 0, width);
+    /// ```
+    pub fn draw_msdf_into<C: CoveragePixel, const N: usize>(
+        &self,
+        spread: f32,
+        target: &mut [[C; N]],
+        offset: usize,
+        stride: usize,
+    ) {
+        assert!(N == 3 || N == 4, "{N} channels, expected 3 or 4");
+        let bounds = self.sdf_px_bounds(spread);
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        if w == 0 || h == 0 {
+            return;
+        }
+        assert!(stride >= w, "stride {stride} < width {w}");
+        let required = offset + (h - 1) * stride + w;
+        assert!(
+            target.len() >= required,
+            "target len {} < {required} required",
+            target.len()
+        );
+
+        self.draw_msdf(spread, |x, y, distances| {
+            let pixel = &mut target[offset + y as usize * stride + x as usize];
+            for (channel, distance) in pixel.iter_mut().zip(distances) {
+                *channel = C::from_coverage(distance_value(distance, spread));
+            }
+        });
+    }
+}
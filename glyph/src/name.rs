@@ -0,0 +1,74 @@
+use alloc::string::String;
+
+/// Identifier of a font [`FontName`] entry, e.g. [`NameId::FAMILY`].
+///
+/// See the [OpenType name IDs](https://learn.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NameId(pub u16);
+
+impl NameId {
+    pub const COPYRIGHT_NOTICE: Self = Self(0);
+    /// Legacy family name, may include a style, e.g. `"Exo 2 Light"`.
+    pub const FAMILY: Self = Self(1);
+    /// Legacy subfamily name, one of `"Regular"`, `"Italic"`, `"Bold"` or `"Bold Italic"`.
+    pub const SUBFAMILY: Self = Self(2);
+    pub const UNIQUE_ID: Self = Self(3);
+    /// Full name, e.g. `"Exo 2 Light"`.
+    pub const FULL_NAME: Self = Self(4);
+    pub const VERSION: Self = Self(5);
+    /// PostScript name, e.g. `"Exo2-Light"`.
+    pub const POST_SCRIPT_NAME: Self = Self(6);
+    pub const TRADEMARK: Self = Self(7);
+    pub const MANUFACTURER: Self = Self(8);
+    pub const DESIGNER: Self = Self(9);
+    pub const DESCRIPTION: Self = Self(10);
+    pub const VENDOR_URL: Self = Self(11);
+    pub const DESIGNER_URL: Self = Self(12);
+    pub const LICENSE: Self = Self(13);
+    pub const LICENSE_URL: Self = Self(14);
+    /// Typographic family name, e.g. `"Exo 2"`.
+    pub const TYPOGRAPHIC_FAMILY: Self = Self(16);
+    /// Typographic subfamily name, e.g. `"Light"`.
+    pub const TYPOGRAPHIC_SUBFAMILY: Self = Self(17);
+    pub const SAMPLE_TEXT: Self = Self(19);
+}
+
+/// US English Windows language ID.
+pub(crate) const ENGLISH_US: u16 = 0x0409;
+
+/// A unicode font name entry, e.g. a localized family name.
+///
+/// See [`Font::names`](crate::Font::names).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontName {
+    pub name_id: NameId,
+    /// Windows language ID (LCID), e.g. `0x0409` for US English or `0x0411` for Japanese.
+    ///
+    /// `None` if the language is not known, e.g. for platform independent unicode names.
+    pub language_id: Option<u16>,
+    pub value: String,
+}
+
+/// Returns the entry in `language_id`, otherwise the same primary language,
+/// otherwise US English, otherwise the first entry.
+pub(crate) fn select_language<T>(
+    mut entries: impl Iterator<Item = T> + Clone,
+    entry_language: impl Fn(&T) -> Option<u16>,
+    language_id: u16,
+) -> Option<T> {
+    let primary = |id: u16| id & 0x3ff;
+    entries
+        .clone()
+        .find(|e| entry_language(e) == Some(language_id))
+        .or_else(|| {
+            entries
+                .clone()
+                .find(|e| entry_language(e).is_some_and(|id| primary(id) == primary(language_id)))
+        })
+        .or_else(|| {
+            entries
+                .clone()
+                .find(|e| entry_language(e) == Some(ENGLISH_US))
+        })
+        .or_else(|| entries.next())
+}
@@ -1,6 +1,10 @@
 #[cfg(all(feature = "libm", not(feature = "std")))]
 use crate::nostd_float::FloatExt;
-use crate::{point, Glyph, Point, PxScaleFactor};
+use crate::{
+    point, CoveragePixel, FillRule, Glyph, LcdFilter, Point, PxScaleFactor, SubpixelLayout,
+    Transform,
+};
+use ab_glyph_rasterizer::Rasterizer;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
@@ -11,9 +15,48 @@ pub struct Outline {
     pub bounds: Rect,
     /// Unscaled & unpositioned outline curves.
     pub curves: Vec<OutlineCurve>,
+    /// Ascending indices of the `curves` starting each contour.
+    pub(crate) contour_starts: Vec<usize>,
 }
 
 impl Outline {
+    /// Creates an outline of `curves`, where each contour starts at the ascending
+    /// `contour_starts` indices. Curves before the first start also form a contour.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// let triangle = |x: f32| {
+    ///     [
+    ///         OutlineCurve::Line(point(x, 0.0), point(x + 5.0, 10.0)),
+    ///         OutlineCurve::Line(point(x + 5.0, 10.0), point(x + 10.0, 0.0)),
+    ///         OutlineCurve::Line(point(x + 10.0, 0.0), point(x, 0.0)),
+    ///     ]
+    /// };
+    /// let curves = [triangle(0.0), triangle(20.0)].concat();
+    /// let bounds = Rect {
+    ///     min: point(0.0, 10.0),
+    ///     max: point(30.0, 0.0),
+    /// };
+    ///
+    /// let outline = Outline::new(bounds, curves, vec![0, 3]);
+    /// assert_eq!(outline.contours().len(), 2);
+    /// ```
+    pub fn new(bounds: Rect, curves: Vec<OutlineCurve>, contour_starts: Vec<usize>) -> Self {
+        Self {
+            bounds,
+            curves,
+            contour_starts,
+        }
+    }
+
+    /// Ascending indices of the [`Self::curves`] starting each contour.
+    /// Curves before the first start also form a contour.
+    #[inline]
+    pub fn contour_starts(&self) -> &[usize] {
+        &self.contour_starts
+    }
+
     /// Convert unscaled bounds into pixel bounds at a given scale & position.
     ///
     /// See [`OutlinedGlyph::px_bounds`].
@@ -36,6 +79,150 @@ impl Outline {
             ),
         }
     }
+
+    /// Applies `transform` to the unscaled outline curves, in font units where y points up,
+    /// & updates the bounds to fit the transformed curves.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let mut outline = font.outline(font.glyph_id('q')).unwrap();
+    /// let width = outline.bounds.width();
+    ///
+    /// // mirror horizontally
+    /// outline.transform(Transform::scale(-1.0, 1.0));
+    /// assert_eq!(outline.bounds.width(), width);
+    /// ```
+    pub fn transform(&mut self, transform: Transform) {
+        for curve in &mut self.curves {
+            *curve = curve.map_points(|p| transform.transform_point(p));
+        }
+
+        let Rect { min, max } = self.bounds;
+        let corners = [min, max, point(min.x, max.y), point(max.x, min.y)];
+        let (x_min, y_min, x_max, y_max) = if self.curves.is_empty() {
+            points_bounds(corners.into_iter().map(|p| transform.transform_point(p)))
+        } else {
+            points_bounds(self.curves.iter().flat_map(OutlineCurve::points))
+        };
+        self.bounds = Rect {
+            min: point(x_min, y_max),
+            max: point(x_max, y_min),
+        };
+    }
+
+    /// Returns the curves of each contour, see [`Self::contour_starts`].
+    pub(crate) fn contour_curves(&self) -> ContourCurves<'_> {
+        ContourCurves {
+            curves: &self.curves,
+            offset: 0,
+            starts: &self.contour_starts,
+        }
+    }
+
+    /// Returns whole number pixel bounds of the outline scaled by `scale_factor`, then
+    /// transformed by `transform` & positioned at `position`.
+    fn px_bounds_transformed(
+        &self,
+        scale_factor: PxScaleFactor,
+        transform: Transform,
+        position: Point,
+    ) -> Rect {
+        if transform == Transform::IDENTITY {
+            return self.px_bounds(scale_factor, position);
+        }
+        let to_px =
+            Transform::scale(scale_factor.horizontal, -scale_factor.vertical).then(transform);
+        let Rect { min, max } = self.bounds;
+        let (x_min, y_min, x_max, y_max) = if self.curves.is_empty() {
+            let corners = [min, max, point(min.x, max.y), point(max.x, min.y)];
+            points_bounds(corners.into_iter().map(|p| to_px.transform_point(p)))
+        } else {
+            points_bounds(
+                self.curves
+                    .iter()
+                    .flat_map(OutlineCurve::points)
+                    .map(|p| to_px.transform_point(p)),
+            )
+        };
+
+        let (x_trunc, x_fract) = (position.x.trunc(), position.x.fract());
+        let (y_trunc, y_fract) = (position.y.trunc(), position.y.fract());
+        Rect {
+            min: point(
+                (x_min + x_fract).floor() + x_trunc,
+                (y_min + y_fract).floor() + y_trunc,
+            ),
+            max: point(
+                (x_max + x_fract).ceil() + x_trunc,
+                (y_max + y_fract).ceil() + y_trunc,
+            ),
+        }
+    }
+}
+
+/// Returns `(x_min, y_min, x_max, y_max)` of `points`.
+pub(crate) fn points_bounds(points: impl Iterator<Item = Point>) -> (f32, f32, f32, f32) {
+    points.fold(
+        (
+            f32::INFINITY,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+        ),
+        |(x_min, y_min, x_max, y_max), p| {
+            (
+                x_min.min(p.x),
+                y_min.min(p.y),
+                x_max.max(p.x),
+                y_max.max(p.y),
+            )
+        },
+    )
+}
+
+/// Returns the unscaled bounds of the control boxes of `curves`, or the default
+/// rect if empty.
+pub(crate) fn curves_bounds(curves: &[OutlineCurve]) -> Rect {
+    if curves.is_empty() {
+        return Rect::default();
+    }
+    let (x_min, y_min, x_max, y_max) = points_bounds(curves.iter().flat_map(OutlineCurve::points));
+    Rect {
+        min: point(x_min, y_max),
+        max: point(x_max, y_min),
+    }
+}
+
+/// Iterator of the non-empty curve slices of each outline contour.
+#[derive(Clone, Debug)]
+pub(crate) struct ContourCurves<'a> {
+    curves: &'a [OutlineCurve],
+    /// Index of `curves[0]` in the outline.
+    offset: usize,
+    /// Contour starts not yet reached.
+    starts: &'a [usize],
+}
+
+impl<'a> Iterator for ContourCurves<'a> {
+    type Item = &'a [OutlineCurve];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.curves.is_empty() {
+            return None;
+        }
+        while self.starts.first().is_some_and(|&s| s <= self.offset) {
+            self.starts = &self.starts[1..];
+        }
+        let len = self.starts.first().map_or(self.curves.len(), |s| {
+            self.curves.len().min(s - self.offset)
+        });
+        let (contour, rest) = self.curves.split_at(len);
+        self.curves = rest;
+        self.offset += len;
+        Some(contour)
+    }
 }
 
 /// A glyph that has been outlined at a scale & position.
@@ -45,9 +232,12 @@ pub struct OutlinedGlyph {
     // Pixel scale bounds.
     px_bounds: Rect,
     // Scale factor
-    scale_factor: PxScaleFactor,
+    pub(crate) scale_factor: PxScaleFactor,
     // Raw outline
-    outline: Outline,
+    pub(crate) outline: Outline,
+    fill_rule: FillRule,
+    flattening_tolerance: f32,
+    transform: Transform,
 }
 
 impl OutlinedGlyph {
@@ -63,9 +253,105 @@ impl OutlinedGlyph {
             px_bounds,
             scale_factor,
             outline,
+            fill_rule: FillRule::default(),
+            flattening_tolerance: <Rasterizer>::DEFAULT_FLATTENING_TOLERANCE,
+            transform: Transform::IDENTITY,
         }
     }
 
+    /// Returns this glyph using a given [`FillRule`] to [`Self::draw`],
+    /// [`FillRule::NonZero`] by default.
+    ///
+    /// Fonts with overlapping contours designed for even-odd filling, like some icon fonts,
+    /// should use [`FillRule::EvenOdd`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let q = font.outline_glyph(glyph).unwrap().with_fill_rule(FillRule::EvenOdd);
+    /// assert_eq!(q.fill_rule(), FillRule::EvenOdd);
+    /// ```
+    #[inline]
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The [`FillRule`] used to [`Self::draw`].
+    #[inline]
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Returns this glyph using a given curve flattening tolerance to [`Self::draw`],
+    /// the approximate max distance in pixels between the outline curves and the lines
+    /// used to draw them. Default [`Rasterizer::DEFAULT_FLATTENING_TOLERANCE`].
+    ///
+    /// Lower values draw more accurate curves, e.g. to avoid faceting in very large text.
+    /// Higher values draw faster, e.g. for small text.
+    ///
+    /// # Panics
+    /// Panics if the tolerance is not positive & finite.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let glyph = font.glyph_id('q').with_scale(500.0);
+    /// let q = font.outline_glyph(glyph).unwrap().with_flattening_tolerance(0.05);
+    /// assert_eq!(q.flattening_tolerance(), 0.05);
+    /// ```
+    #[inline]
+    pub fn with_flattening_tolerance(mut self, tolerance: f32) -> Self {
+        assert!(
+            tolerance > 0.0 && tolerance.is_finite(),
+            "invalid flattening tolerance {tolerance}"
+        );
+        self.flattening_tolerance = tolerance;
+        self
+    }
+
+    /// The curve flattening tolerance in pixels used to [`Self::draw`].
+    #[inline]
+    pub fn flattening_tolerance(&self) -> f32 {
+        self.flattening_tolerance
+    }
+
+    /// Returns this glyph with the scaled outline transformed by `transform`, e.g. to draw
+    /// rotated, skewed or mirrored text. [`Self::px_bounds`] are updated to fit.
+    ///
+    /// The transform applies in pixel coordinates, where y points down, relative to the
+    /// [`Glyph::position`]. So transforms like [`Transform::rotate`] pivot around the glyph
+    /// position on the baseline. Replaces any previous transform.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let rotated_q = font
+    ///     .outline_glyph(glyph)
+    ///     .unwrap()
+    ///     .with_transform(Transform::rotate(core::f32::consts::FRAC_PI_4));
+    /// rotated_q.draw(|x, y, c| { /* draw pixel `(x, y)` with coverage: `c` */ });
+    /// ```
+    #[inline]
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self.px_bounds =
+            self.outline
+                .px_bounds_transformed(self.scale_factor, transform, self.glyph.position);
+        self
+    }
+
+    /// The [`Transform`] applied to the scaled outline, see [`Self::with_transform`].
+    #[inline]
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
     /// Glyph info.
     #[inline]
     pub fn glyph(&self) -> &Glyph {
@@ -103,55 +389,215 @@ impl OutlinedGlyph {
     /// with a coverage value indicating how much the glyph covered that pixel.
     ///
     /// A coverage value of `0.0` means the pixel is totally uncovered by the glyph.
-    /// A value of `1.0` or greater means fully covered.
+    /// A value of `1.0` means fully covered, according to the [`Self::fill_rule`].
+    ///
+    /// With the `std` feature a thread-local [`Rasterizer`] is reused to avoid allocating
+    /// for each glyph, see [`Self::draw_with`].
     pub fn draw<O: FnMut(u32, u32, f32)>(&self, o: O) {
-        use ab_glyph_rasterizer::Rasterizer;
-        let h_factor = self.scale_factor.horizontal;
-        let v_factor = -self.scale_factor.vertical;
+        with_pooled_rasterizer(|rasterizer| self.draw_with(rasterizer, o));
+    }
+
+    /// Draw this glyph outline using a pixel & coverage handling function, like [`Self::draw`],
+    /// reusing a [`Rasterizer`] to avoid allocation.
+    ///
+    /// The `rasterizer` is reset to the [`Self::px_bounds`] dimensions, [`Self::fill_rule`]
+    /// & [`Self::flattening_tolerance`],
+    /// so can be reused to draw any number of glyphs.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let mut rasterizer = Rasterizer::new(0, 0);
+    /// for c in "abc".chars() {
+    ///     let glyph = font.glyph_id(c).with_scale(24.0);
+    ///     let outlined = font.outline_glyph(glyph).unwrap();
+    ///     outlined.draw_with(&mut rasterizer, |x, y, c| { /* draw pixel `(x, y)` with coverage: `c` */ });
+    /// }
+    /// ```
+    pub fn draw_with<O: FnMut(u32, u32, f32)>(&self, rasterizer: &mut Rasterizer, o: O) {
+        self.rasterize(rasterizer);
+        rasterizer.for_each_pixel_2d(o);
+    }
+
+    /// Draw this glyph outline into a `target` pixel buffer, writing each row `y`
+    /// of the [`Self::px_bounds`] sized image to `target[offset + y * stride..][..width]`.
+    ///
+    /// Coverage is converted into the [`CoveragePixel`] format, e.g. `u8` alpha values
+    /// rounded from `0.0..=1.0` coverage to `0..=255`.
+    ///
+    /// # Panics
+    /// Panics if `stride` is less than the width or `target` is too small to hold all rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let q = font.outline_glyph(glyph).unwrap();
+    ///
+    /// // draw into an 8-bit 256x256 atlas at position (10, 20)
+    /// let mut atlas = vec![0u8; 256 * 256];
+    /// q.draw_into(&mut atlas, 20 * 256 + 10, 256);
+    /// ```
+    pub fn draw_into<C: CoveragePixel>(&self, target: &mut [C], offset: usize, stride: usize) {
+        with_pooled_rasterizer(|rasterizer| {
+            self.rasterize(rasterizer);
+            rasterizer.write_coverage(target, offset, stride);
+        });
+    }
+
+    /// Pixel bounding box for this glyph outline when drawn with [`Self::draw_lcd`].
+    ///
+    /// These are the [`Self::px_bounds`] expanded by 1 pixel on each side in the
+    /// direction of the `layout` subpixels to fit the filtered coverage.
+    pub fn lcd_px_bounds(&self, layout: SubpixelLayout) -> Rect {
+        let Rect { min, max } = self.px_bounds;
+        let pad = if layout.is_vertical() {
+            point(0.0, 1.0)
+        } else {
+            point(1.0, 0.0)
+        };
+        Rect {
+            min: min - pad,
+            max: max + pad,
+        }
+    }
+
+    /// Draw this glyph outline for an LCD panel using a pixel & `[r, g, b]` coverage handling
+    /// function.
+    ///
+    /// The outline is rasterized at 3x resolution in the `layout` subpixel direction, then
+    /// smoothed by the `filter` to reduce colour fringing.
+    /// The callback will be called for each `(x, y)` pixel coordinate inside the
+    /// [`Self::lcd_px_bounds`] with a coverage value for each colour channel.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let q = font.outline_glyph(glyph).unwrap();
+    /// q.draw_lcd(SubpixelLayout::Rgb, LcdFilter::DEFAULT, |x, y, [r, g, b]| {
+    ///     // blend text colour into pixel `(x, y)` using per-channel coverage
+    /// });
+    /// ```
+    pub fn draw_lcd<O: FnMut(u32, u32, [f32; 3])>(
+        &self,
+        layout: SubpixelLayout,
+        filter: LcdFilter,
+        o: O,
+    ) {
+        let bounds = self.lcd_px_bounds(layout);
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        // 3 samples per pixel in the subpixel direction, offset by the 1px padding
+        let (dimensions, scale, offset) = if layout.is_vertical() {
+            ((w, h * 3), point(1.0, 3.0), point(0.0, 3.0))
+        } else {
+            ((w * 3, h), point(3.0, 1.0), point(3.0, 0.0))
+        };
+
+        with_pooled_rasterizer(|rasterizer| {
+            self.rasterize_scaled(rasterizer, dimensions, scale, offset);
+            rasterizer.for_each_lcd_pixel_2d(layout, filter, o);
+        });
+    }
+
+    /// Returns the outline curves in pixel coordinates relative to the `px_bounds` min,
+    /// translated by `offset`.
+    pub(crate) fn px_curves(&self, offset: Point) -> impl Iterator<Item = OutlineCurve> + '_ {
+        let to_px = self.px_transform(point(1.0, 1.0), offset);
+        self.outline
+            .curves
+            .iter()
+            .map(move |curve| curve.map_points(|p| to_px.transform_point(p)))
+    }
+
+    /// Returns the transform from unscaled outline coordinates to pixel coordinates relative
+    /// to the `px_bounds` min, scaled by `grid_scale` & translated by `grid_offset`.
+    fn px_transform(&self, grid_scale: Point, grid_offset: Point) -> Transform {
         let offset = self.glyph.position - self.px_bounds.min;
-        let (w, h) = (
+        let offset = point(offset.x * grid_scale.x, offset.y * grid_scale.y) + grid_offset;
+        Transform::scale(self.scale_factor.horizontal, -self.scale_factor.vertical)
+            .then(self.transform)
+            .then(Transform::scale(grid_scale.x, grid_scale.y))
+            .then(Transform::translate(offset.x, offset.y))
+    }
+
+    /// Resets the rasterizer to `px_bounds` size & draws the outline curves.
+    fn rasterize(&self, rasterizer: &mut Rasterizer) {
+        let dimensions = (
             self.px_bounds.width() as usize,
             self.px_bounds.height() as usize,
         );
+        self.rasterize_scaled(rasterizer, dimensions, point(1.0, 1.0), point(0.0, 0.0));
+    }
 
-        let scale_up = |&Point { x, y }| point(x * h_factor, y * v_factor);
+    /// Resets the rasterizer to `dimensions` & draws the outline curves scaled by
+    /// `grid_scale` pixel samples per pixel & translated by `grid_offset`.
+    fn rasterize_scaled(
+        &self,
+        rasterizer: &mut Rasterizer,
+        (w, h): (usize, usize),
+        grid_scale: Point,
+        grid_offset: Point,
+    ) {
+        let to_px = self.px_transform(grid_scale, grid_offset);
+        let px = |&p: &Point| to_px.transform_point(p);
 
-        self.outline
-            .curves
-            .iter()
-            .fold(Rasterizer::new(w, h), |mut rasterizer, curve| match curve {
-                OutlineCurve::Line(p0, p1) => {
-                    // eprintln!("r.draw_line({:?}, {:?});",
-                    //     scale_up(p0) + offset, scale_up(p1) + offset);
-                    rasterizer.draw_line(scale_up(p0) + offset, scale_up(p1) + offset);
-                    rasterizer
-                }
-                OutlineCurve::Quad(p0, p1, p2) => {
-                    // eprintln!("r.draw_quad({:?}, {:?}, {:?});",
-                    //     scale_up(p0) + offset, scale_up(p1) + offset, scale_up(p2) + offset);
-                    rasterizer.draw_quad(
-                        scale_up(p0) + offset,
-                        scale_up(p1) + offset,
-                        scale_up(p2) + offset,
-                    );
-                    rasterizer
-                }
-                OutlineCurve::Cubic(p0, p1, p2, p3) => {
-                    // eprintln!("r.draw_cubic({:?}, {:?}, {:?}, {:?});",
-                    //     scale_up(p0) + offset, scale_up(p1) + offset, scale_up(p2) + offset, scale_up(p3) + offset);
-                    rasterizer.draw_cubic(
-                        scale_up(p0) + offset,
-                        scale_up(p1) + offset,
-                        scale_up(p2) + offset,
-                        scale_up(p3) + offset,
-                    );
-                    rasterizer
-                }
-            })
-            .for_each_pixel_2d(o);
+        rasterizer.reset(w, h);
+        rasterizer.set_fill_rule(self.fill_rule);
+        rasterizer.set_flattening_tolerance(self.flattening_tolerance);
+
+        self.outline.curves.iter().for_each(|curve| match curve {
+            OutlineCurve::Line(p0, p1) => {
+                // eprintln!("r.draw_line({:?}, {:?});",
+                //     px(p0), px(p1));
+                rasterizer.draw_line(px(p0), px(p1));
+            }
+            OutlineCurve::Quad(p0, p1, p2) => {
+                // eprintln!("r.draw_quad({:?}, {:?}, {:?});",
+                //     px(p0), px(p1), px(p2));
+                rasterizer.draw_quad(px(p0), px(p1), px(p2));
+            }
+            OutlineCurve::Cubic(p0, p1, p2, p3) => {
+                // eprintln!("r.draw_cubic({:?}, {:?}, {:?}, {:?});",
+                //     px(p0), px(p1), px(p2), px(p3));
+                rasterizer.draw_cubic(px(p0), px(p1), px(p2), px(p3));
+            }
+        })
     }
 }
 
+/// Max pixel area of a rasterizer kept for reuse by [`with_pooled_rasterizer`],
+/// larger rasterizers are dropped to avoid holding onto lots of memory.
+#[cfg(feature = "std")]
+const POOLED_RASTERIZER_MAX_AREA: usize = 512 * 512;
+
+/// Runs `f` with this thread's pooled rasterizer, or a new one if unavailable
+/// (e.g. when called re-entrantly from a draw callback).
+#[cfg(feature = "std")]
+fn with_pooled_rasterizer<R>(f: impl FnOnce(&mut Rasterizer) -> R) -> R {
+    use std::cell::Cell;
+
+    std::thread_local! {
+        static POOL: Cell<Option<Rasterizer>> = const { Cell::new(None) };
+    }
+
+    let mut rasterizer = POOL.take().unwrap_or_else(|| Rasterizer::new(0, 0));
+    let out = f(&mut rasterizer);
+    let (w, h) = rasterizer.dimensions();
+    if w * h <= POOLED_RASTERIZER_MAX_AREA {
+        POOL.set(Some(rasterizer));
+    }
+    out
+}
+
+#[cfg(not(feature = "std"))]
+fn with_pooled_rasterizer<R>(f: impl FnOnce(&mut Rasterizer) -> R) -> R {
+    f(&mut Rasterizer::new(0, 0))
+}
+
 impl AsRef<Glyph> for OutlinedGlyph {
     #[inline]
     fn as_ref(&self) -> &Glyph {
@@ -160,7 +606,7 @@ impl AsRef<Glyph> for OutlinedGlyph {
 }
 
 /// Glyph outline primitives.
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum OutlineCurve {
     /// Straight line from `.0` to `.1`.
     Line(Point, Point),
@@ -171,6 +617,47 @@ pub enum OutlineCurve {
     Cubic(Point, Point, Point, Point),
 }
 
+impl OutlineCurve {
+    /// Returns the curve with each point mapped by `f`.
+    #[inline]
+    pub(crate) fn map_points(&self, mut f: impl FnMut(Point) -> Point) -> Self {
+        match *self {
+            Self::Line(p0, p1) => Self::Line(f(p0), f(p1)),
+            Self::Quad(p0, p1, p2) => Self::Quad(f(p0), f(p1), f(p2)),
+            Self::Cubic(p0, p1, p2, p3) => Self::Cubic(f(p0), f(p1), f(p2), f(p3)),
+        }
+    }
+
+    /// Returns the curve start point.
+    #[inline]
+    pub(crate) fn start(&self) -> Point {
+        match *self {
+            Self::Line(p0, _) | Self::Quad(p0, ..) | Self::Cubic(p0, ..) => p0,
+        }
+    }
+
+    /// Returns the curve end point.
+    #[inline]
+    pub(crate) fn end(&self) -> Point {
+        match *self {
+            Self::Line(_, p1) => p1,
+            Self::Quad(.., p2) => p2,
+            Self::Cubic(.., p3) => p3,
+        }
+    }
+
+    /// Returns the curve end & control points.
+    #[inline]
+    pub(crate) fn points(&self) -> impl Iterator<Item = Point> + Clone {
+        let (points, len) = match *self {
+            Self::Line(p0, p1) => ([p0, p1, p1, p1], 2),
+            Self::Quad(p0, p1, p2) => ([p0, p1, p2, p2], 3),
+            Self::Cubic(p0, p1, p2, p3) => ([p0, p1, p2, p3], 4),
+        };
+        points.into_iter().take(len)
+    }
+}
+
 /// A rectangle, with top-left corner at `min`, and bottom-right corner at `max`.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct Rect {
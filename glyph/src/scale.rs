@@ -1,6 +1,8 @@
 #[cfg(all(feature = "libm", not(feature = "std")))]
 use crate::nostd_float::FloatExt;
-use crate::{Font, Glyph, GlyphId, OutlinedGlyph, Rect};
+use crate::{
+    DecorationMetrics, Font, Glyph, GlyphId, LineMetrics, LineMetricsMode, OutlinedGlyph, Rect,
+};
 
 /// Pixel scale.
 ///
@@ -129,6 +131,59 @@ pub trait ScaleFont<F: Font> {
         self.v_scale_factor() * self.font().line_gap_unscaled()
     }
 
+    /// Pixel scaled ascent, descent & line gap from the `mode` source,
+    /// see [`Font::line_metrics_unscaled`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # fn main() -> Result<(), InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/OpenSans-Italic.ttf"))?;
+    /// let scaled_font = font.as_scaled(24.0);
+    ///
+    /// let lines = scaled_font.line_metrics(LineMetricsMode::Typo);
+    /// let next_baseline_y = lines.line_height();
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    fn line_metrics(&self, mode: LineMetricsMode) -> LineMetrics {
+        self.font()
+            .line_metrics_unscaled(mode)
+            .scaled(self.v_scale_factor())
+    }
+
+    /// Pixel scaled height of lowercase letters, like 'x', above the baseline.
+    /// See [`Os2Metrics::x_height`](crate::Os2Metrics::x_height).
+    #[inline]
+    fn x_height(&self) -> Option<f32> {
+        let x_height = self.font().os2_metrics()?.x_height?;
+        Some(self.v_scale_factor() * x_height)
+    }
+
+    /// Pixel scaled height of uppercase letters above the baseline.
+    /// See [`Os2Metrics::cap_height`](crate::Os2Metrics::cap_height).
+    #[inline]
+    fn cap_height(&self) -> Option<f32> {
+        let cap_height = self.font().os2_metrics()?.cap_height?;
+        Some(self.v_scale_factor() * cap_height)
+    }
+
+    /// Pixel scaled underline position & thickness,
+    /// see [`Font::underline_metrics_unscaled`].
+    #[inline]
+    fn underline_metrics(&self) -> Option<DecorationMetrics> {
+        let metrics = self.font().underline_metrics_unscaled()?;
+        Some(metrics.scaled(self.v_scale_factor()))
+    }
+
+    /// Pixel scaled strikeout position & thickness,
+    /// see [`Font::strikeout_metrics_unscaled`].
+    #[inline]
+    fn strikeout_metrics(&self) -> Option<DecorationMetrics> {
+        let metrics = self.font().strikeout_metrics_unscaled()?;
+        Some(metrics.scaled(self.v_scale_factor()))
+    }
+
     /// Lookup a `GlyphId` matching a given `char`.
     #[inline]
     fn glyph_id(&self, c: char) -> GlyphId {
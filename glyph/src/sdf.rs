@@ -0,0 +1,481 @@
+//! Signed distance field generation from outline curves.
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{point, CoveragePixel, FillRule, OutlineCurve, OutlinedGlyph, Point, Rect};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Max distance in pixels between edges & the lines used to determine winding.
+const WINDING_FLATTENING_TOLERANCE: f32 = 0.05;
+/// Max lines used to flatten a single edge for winding.
+const MAX_WINDING_LINES: f32 = 1024.0;
+/// Evenly spaced starting params used to search for the closest point on a cubic.
+const CUBIC_SEARCH_STARTS: u8 = 4;
+/// Newton iterations refining each cubic closest point search.
+const CUBIC_SEARCH_STEPS: u8 = 4;
+
+#[inline]
+pub(crate) fn dot(a: Point, b: Point) -> f32 {
+    a.x * b.x + a.y * b.y
+}
+
+#[inline]
+pub(crate) fn cross(a: Point, b: Point) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+#[inline]
+pub(crate) fn length(v: Point) -> f32 {
+    dot(v, v).sqrt()
+}
+
+/// An outline curve in pixel coordinates.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Edge(pub(crate) OutlineCurve);
+
+impl Edge {
+    /// Returns the curve start point.
+    #[inline]
+    pub(crate) fn start(&self) -> Point {
+        self.0.start()
+    }
+
+    /// Returns the curve end point.
+    #[inline]
+    pub(crate) fn end(&self) -> Point {
+        self.0.end()
+    }
+
+    /// Returns the point on the curve at param `t` in `0.0..=1.0`.
+    pub(crate) fn point(&self, t: f32) -> Point {
+        match self.0 {
+            OutlineCurve::Line(p0, p1) => p0 + (p1 - p0) * t,
+            OutlineCurve::Quad(p0, p1, p2) => {
+                let mt = 1.0 - t;
+                p0 * (mt * mt) + p1 * (2.0 * mt * t) + p2 * (t * t)
+            }
+            OutlineCurve::Cubic(p0, p1, p2, p3) => {
+                let mt = 1.0 - t;
+                p0 * (mt * mt * mt)
+                    + p1 * (3.0 * mt * mt * t)
+                    + p2 * (3.0 * mt * t * t)
+                    + p3 * (t * t * t)
+            }
+        }
+    }
+
+    /// Returns the curve derivative at param `t`, scaled down by the curve degree.
+    fn derivative(&self, t: f32) -> Point {
+        match self.0 {
+            OutlineCurve::Line(p0, p1) => p1 - p0,
+            OutlineCurve::Quad(p0, p1, p2) => (p1 - p0) * (1.0 - t) + (p2 - p1) * t,
+            OutlineCurve::Cubic(p0, p1, p2, p3) => {
+                let mt = 1.0 - t;
+                (p1 - p0) * (mt * mt) + (p2 - p1) * (2.0 * mt * t) + (p3 - p2) * (t * t)
+            }
+        }
+    }
+
+    /// Returns the curve direction at param `t`, falling back to the chord direction
+    /// for degenerate control points.
+    pub(crate) fn direction(&self, t: f32) -> Point {
+        let d = self.derivative(t);
+        if d == point(0.0, 0.0) {
+            return self.end() - self.start();
+        }
+        d
+    }
+
+    /// Splits the curve into 3 equal param parts.
+    pub(crate) fn split_in_thirds(&self) -> [Edge; 3] {
+        [(0.0, 1.0 / 3.0), (1.0 / 3.0, 2.0 / 3.0), (2.0 / 3.0, 1.0)].map(|(a, b)| {
+            let (pa, pb) = (self.point(a), self.point(b));
+            Edge(match self.0 {
+                OutlineCurve::Line(..) => OutlineCurve::Line(pa, pb),
+                OutlineCurve::Quad(..) => {
+                    OutlineCurve::Quad(pa, pa + self.derivative(a) * (b - a), pb)
+                }
+                OutlineCurve::Cubic(..) => OutlineCurve::Cubic(
+                    pa,
+                    pa + self.derivative(a) * (b - a),
+                    pb - self.derivative(b) * (b - a),
+                    pb,
+                ),
+            })
+        })
+    }
+
+    /// Returns the min & max of the control points, which bound the curve.
+    fn bounds(&self) -> (Point, Point) {
+        let (mut min, mut max) = (self.start(), self.start());
+        let mut extend = |p: Point| {
+            min = point(min.x.min(p.x), min.y.min(p.y));
+            max = point(max.x.max(p.x), max.y.max(p.y));
+        };
+        match self.0 {
+            OutlineCurve::Line(_, p1) => extend(p1),
+            OutlineCurve::Quad(_, p1, p2) => [p1, p2].into_iter().for_each(extend),
+            OutlineCurve::Cubic(_, p1, p2, p3) => [p1, p2, p3].into_iter().for_each(extend),
+        }
+        (min, max)
+    }
+
+    /// Returns the distance from `p` to the closest point on the curve & the param `t`
+    /// of that point.
+    pub(crate) fn distance(&self, p: Point) -> (f32, f32) {
+        match self.0 {
+            OutlineCurve::Line(p0, p1) => {
+                let d = p1 - p0;
+                let len_sq = dot(d, d);
+                let t = if len_sq > 0.0 {
+                    (dot(p - p0, d) / len_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (length(p0 + d * t - p), t)
+            }
+            OutlineCurve::Quad(p0, p1, p2) => {
+                // closest points are where (B(t) - p).B'(t) = 0, a cubic in t
+                let qa = p0 - p;
+                let ab = p1 - p0;
+                let br = p2 - p1 - ab;
+                let roots = solve_cubic(
+                    dot(br, br),
+                    3.0 * dot(ab, br),
+                    2.0 * dot(ab, ab) + dot(qa, br),
+                    dot(qa, ab),
+                );
+                let mut best = (length(qa), 0.0);
+                for t in roots.into_iter().chain([1.0]) {
+                    if (0.0..=1.0).contains(&t) {
+                        let dist = length(self.point(t) - p);
+                        if dist < best.0 {
+                            best = (dist, t);
+                        }
+                    }
+                }
+                best
+            }
+            OutlineCurve::Cubic(p0, p1, p2, p3) => {
+                let qa = p0 - p;
+                let ab = p1 - p0;
+                let br = p2 - p1 - ab;
+                let az = (p3 - p2) - (p2 - p1) - br;
+
+                let mut best = (length(qa), 0.0);
+                let end_dist = length(p3 - p);
+                if end_dist < best.0 {
+                    best = (end_dist, 1.0);
+                }
+
+                // Newton's method on (B(t) - p).B'(t) from evenly spaced starting params
+                for start in 0..=CUBIC_SEARCH_STARTS {
+                    let mut t = f32::from(start) / f32::from(CUBIC_SEARCH_STARTS);
+                    for _ in 0..CUBIC_SEARCH_STEPS {
+                        let qe = qa + ab * (3.0 * t) + br * (3.0 * t * t) + az * (t * t * t);
+                        let d1 = ab * 3.0 + br * (6.0 * t) + az * (3.0 * t * t);
+                        let d2 = br * 6.0 + az * (6.0 * t);
+                        let denom = dot(d1, d1) + dot(qe, d2);
+                        if denom == 0.0 {
+                            break;
+                        }
+                        t -= dot(qe, d1) / denom;
+                        if !(0.0..=1.0).contains(&t) {
+                            break;
+                        }
+                        let dist = length(self.point(t) - p);
+                        if dist < best.0 {
+                            best = (dist, t);
+                        }
+                    }
+                }
+                best
+            }
+        }
+    }
+
+    /// Returns the distance from `p` to the curve bounds, a lower bound of [`Self::distance`].
+    #[inline]
+    pub(crate) fn bounds_distance(&self, p: Point) -> f32 {
+        let (min, max) = self.bounds();
+        let dx = (min.x - p.x).max(p.x - max.x).max(0.0);
+        let dy = (min.y - p.y).max(p.y - max.y).max(0.0);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Calls `line_fn` for each line approximating the curve.
+    fn flatten(&self, mut line_fn: impl FnMut(Point, Point)) {
+        let dev_len = |p0: Point, p1: Point, p2: Point| length(p0 - p1 * 2.0 + p2);
+        let n = match self.0 {
+            OutlineCurve::Line(..) => 1.0,
+            // max error of `n` lines is `|dev| / (4 * n^2)`
+            OutlineCurve::Quad(p0, p1, p2) => {
+                (dev_len(p0, p1, p2) / (4.0 * WINDING_FLATTENING_TOLERANCE)).sqrt()
+            }
+            // max error of `n` lines is `0.75 * max(|dev|) / n^2`
+            OutlineCurve::Cubic(p0, p1, p2, p3) => {
+                let dev = dev_len(p0, p1, p2).max(dev_len(p1, p2, p3));
+                (0.75 * dev / WINDING_FLATTENING_TOLERANCE).sqrt()
+            }
+        };
+        let n = n.ceil().clamp(1.0, MAX_WINDING_LINES) as u32;
+
+        let mut last = self.start();
+        for i in 1..n {
+            let p = self.point(i as f32 / n as f32);
+            line_fn(last, p);
+            last = p;
+        }
+        line_fn(last, self.end());
+    }
+}
+
+/// Outline edges in pixel coordinates supporting signed distance queries.
+#[derive(Debug)]
+pub(crate) struct EdgeShape {
+    pub(crate) edges: Vec<Edge>,
+    /// Flattened edges used to determine winding.
+    lines: Vec<(Point, Point)>,
+    fill_rule: FillRule,
+}
+
+impl EdgeShape {
+    pub(crate) fn new(edges: Vec<Edge>, fill_rule: FillRule) -> Self {
+        let mut lines = Vec::with_capacity(edges.len());
+        for edge in &edges {
+            edge.flatten(|p0, p1| lines.push((p0, p1)));
+        }
+        Self {
+            edges,
+            lines,
+            fill_rule,
+        }
+    }
+
+    /// Returns the signed area enclosed by the edges, positive for clockwise
+    /// outer contours in pixel coordinates (y down).
+    pub(crate) fn area(&self) -> f32 {
+        self.lines
+            .iter()
+            .map(|(p0, p1)| cross(*p0, *p1))
+            .sum::<f32>()
+            / 2.0
+    }
+
+    /// Returns `true` if `p` is inside the shape according to the fill rule.
+    pub(crate) fn is_inside(&self, p: Point) -> bool {
+        let mut winding = 0_i32;
+        for (p0, p1) in &self.lines {
+            if (p0.y <= p.y) != (p1.y <= p.y) {
+                let x = p0.x + (p.y - p0.y) / (p1.y - p0.y) * (p1.x - p0.x);
+                if x > p.x {
+                    winding += if p1.y > p0.y { 1 } else { -1 };
+                }
+            }
+        }
+        match self.fill_rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the signed distance from `p` to the closest edge, positive inside,
+    /// clamped to `-max..=max`.
+    pub(crate) fn signed_distance(&self, p: Point, max: f32) -> f32 {
+        let mut min_dist = max;
+        for edge in &self.edges {
+            if edge.bounds_distance(p) < min_dist {
+                min_dist = min_dist.min(edge.distance(p).0);
+            }
+        }
+        if self.is_inside(p) {
+            min_dist
+        } else {
+            -min_dist
+        }
+    }
+}
+
+impl OutlinedGlyph {
+    /// Pixel bounding box for this glyph outline when drawn with [`Self::draw_sdf`].
+    ///
+    /// These are the [`Self::px_bounds`] expanded by `spread` (rounded up) pixels on each side
+    /// to fit the distance field outside the outline.
+    pub fn sdf_px_bounds(&self, spread: f32) -> Rect {
+        let pad = spread.ceil().max(0.0);
+        let Rect { min, max } = self.px_bounds();
+        Rect {
+            min: point(min.x - pad, min.y - pad),
+            max: point(max.x + pad, max.y + pad),
+        }
+    }
+
+    /// Draw a signed distance field of this glyph outline using a pixel & distance handling
+    /// function.
+    ///
+    /// The callback will be called for each `(x, y)` pixel coordinate inside the
+    /// [`Self::sdf_px_bounds`] with the distance in pixels from the pixel centre to the
+    /// closest point of the outline, positive inside & negative outside the glyph.
+    /// Distances are clamped to `-spread..=spread`, so are signed zeros for a zero or negative
+    /// `spread`.
+    ///
+    /// Distances are computed from the outline curves directly, so are exact for lines
+    /// & quadratic curves and accurate to a small fraction of a pixel for cubics.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let q = font.outline_glyph(glyph).unwrap();
+    /// q.draw_sdf(4.0, |x, y, distance| { /* e.g. inside if distance > 0.0 */ });
+    /// ```
+    pub fn draw_sdf<O: FnMut(u32, u32, f32)>(&self, spread: f32, mut o: O) {
+        let bounds = self.sdf_px_bounds(spread);
+        let (w, h) = (bounds.width() as u32, bounds.height() as u32);
+        let shape = self.px_edge_shape(self.px_bounds().min - bounds.min);
+        let max = spread.max(0.0);
+
+        for y in 0..h {
+            for x in 0..w {
+                let center = point(x as f32 + 0.5, y as f32 + 0.5);
+                o(x, y, shape.signed_distance(center, max));
+            }
+        }
+    }
+
+    /// Draw a signed distance field of this glyph outline into a `target` pixel buffer,
+    /// writing each row `y` of the [`Self::sdf_px_bounds`] sized image to
+    /// `target[offset + y * stride..][..width]`.
+    ///
+    /// Distances are mapped to `0.0..=1.0` values, `0.5` on the outline increasing inside,
+    /// reaching `0.0` & `1.0` at `spread` pixels outside & inside.
+    /// These are converted into the [`CoveragePixel`] format, e.g. `u8` values `0..=255`
+    /// as commonly used for GPU text rendering with a `0.5` threshold.
+    ///
+    /// A zero or negative `spread` draws a hard edge, `1.0` inside & `0.0` outside the glyph.
+    ///
+    /// # Panics
+    /// Panics if `stride` is less than the width or `target` is too small to hold all rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// # let glyph = font.glyph_id('q').with_scale(24.0);
+    /// let q = font.outline_glyph(glyph).unwrap();
+    /// let bounds = q.sdf_px_bounds(4.0);
+    /// let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+    ///
+    /// let mut sdf = vec![0u8; w * h];
+    /// q.draw_sdf_into(4.0, &mut sdf, 0, w);
+    /// ```
+    pub fn draw_sdf_into<C: CoveragePixel>(
+        &self,
+        spread: f32,
+        target: &mut [C],
+        offset: usize,
+        stride: usize,
+    ) {
+        let bounds = self.sdf_px_bounds(spread);
+        let (w, h) = (bounds.width() as usize, bounds.height() as usize);
+        if w == 0 || h == 0 {
+            return;
+        }
+        assert!(stride >= w, "stride {stride} < width {w}");
+        let required = offset + (h - 1) * stride + w;
+        assert!(
+            target.len() >= required,
+            "target len {} < {required} required",
+            target.len()
+        );
+
+        self.draw_sdf(spread, |x, y, distance| {
+            let value = distance_value(distance, spread);
+            target[offset + y as usize * stride + x as usize] = C::from_coverage(value);
+        });
+    }
+
+    /// Returns the outline edges in pixel coordinates relative to the `px_bounds`
+    /// min offset by `offset`.
+    pub(crate) fn px_edge_shape(&self, offset: Point) -> EdgeShape {
+        let edges = self.px_curves(offset).map(Edge).collect();
+        EdgeShape::new(edges, self.fill_rule())
+    }
+}
+
+/// Maps a signed pixel `distance` to `0.0..=1.0`, `0.5` on the outline reaching `0.0` & `1.0`
+/// at `spread` pixels outside & inside. Distances clamped to a zero or negative `spread` are
+/// signed zeros, mapped to a hard edge.
+#[inline]
+pub(crate) fn distance_value(distance: f32, spread: f32) -> f32 {
+    if spread > 0.0 {
+        (0.5 + distance / (2.0 * spread)).clamp(0.0, 1.0)
+    } else if distance.is_sign_positive() {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Returns real roots of `a*x^2 + b*x + c = 0`.
+fn solve_quadratic(a: f32, b: f32, c: f32) -> impl Iterator<Item = f32> {
+    let mut roots = [f32::NAN; 2];
+    if a.abs() < 1e-12 {
+        if b != 0.0 {
+            roots[0] = -c / b;
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let sqrt_d = discriminant.sqrt();
+            roots = [(-b + sqrt_d) / (2.0 * a), (-b - sqrt_d) / (2.0 * a)];
+        }
+    }
+    roots.into_iter().filter(|r| !r.is_nan())
+}
+
+/// Returns real roots of `a*x^3 + b*x^2 + c*x + d = 0`, NaN for non-roots.
+fn solve_cubic(a: f32, b: f32, c: f32, d: f32) -> [f32; 3] {
+    if a != 0.0 {
+        let bn = b / a;
+        // otherwise treat as quadratic
+        if bn.abs() < 1e6 {
+            return solve_cubic_normed(bn, c / a, d / a);
+        }
+    }
+    let mut roots = [f32::NAN; 3];
+    for (root, r) in roots.iter_mut().zip(solve_quadratic(b, c, d)) {
+        *root = r;
+    }
+    roots
+}
+
+/// Returns real roots of `x^3 + a*x^2 + b*x + c = 0`, NaN for non-roots.
+fn solve_cubic_normed(a: f32, b: f32, c: f32) -> [f32; 3] {
+    const TAU: f32 = core::f32::consts::TAU;
+    let a2 = a * a;
+    let q = (a2 - 3.0 * b) / 9.0;
+    let r = (a * (2.0 * a2 - 9.0 * b) + 27.0 * c) / 54.0;
+    let r2 = r * r;
+    let q3 = q * q * q;
+    let a = a / 3.0;
+    if r2 < q3 {
+        let t = (r / q3.sqrt()).clamp(-1.0, 1.0).acos();
+        let q = -2.0 * q.sqrt();
+        [
+            q * (t / 3.0).cos() - a,
+            q * ((t + TAU) / 3.0).cos() - a,
+            q * ((t - TAU) / 3.0).cos() - a,
+        ]
+    } else {
+        let u = -r.signum() * (r.abs() + (r2 - q3).sqrt()).cbrt();
+        let v = if u == 0.0 { 0.0 } else { q / u };
+        let x2 = if (0.5 * 3.0_f32.sqrt() * (u - v)).abs() < 1e-6 {
+            -0.5 * (u + v) - a
+        } else {
+            f32::NAN
+        };
+        [(u + v) - a, x2, f32::NAN]
+    }
+}
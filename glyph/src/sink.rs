@@ -0,0 +1,102 @@
+use crate::{Outline, OutlineCurve, Point};
+
+/// Receiver of glyph outline path commands, e.g. to build paths for another
+/// graphics library without allocating an [`Outline`](crate::Outline).
+///
+/// Each contour starts with [`Self::move_to`] & ends with [`Self::close`].
+/// Points are unscaled & unpositioned in font units where y points up.
+///
+/// See [`Font::outline_into`](crate::Font::outline_into).
+///
+/// # Example
+/// ```
+/// use ab_glyph::{point, Font, FontRef, OutlineSink, Point};
+///
+/// /// Svg path data builder.
+/// #[derive(Default)]
+/// struct PathData(String);
+///
+/// impl OutlineSink for PathData {
+///     fn move_to(&mut self, to: Point) {
+///         self.0 += &format!("M{} {}", to.x, -to.y);
+///     }
+///     fn line_to(&mut self, to: Point) {
+///         self.0 += &format!("L{} {}", to.x, -to.y);
+///     }
+///     fn quad_to(&mut self, c: Point, to: Point) {
+///         self.0 += &format!("Q{} {} {} {}", c.x, -c.y, to.x, -to.y);
+///     }
+///     fn cubic_to(&mut self, c1: Point, c2: Point, to: Point) {
+///         self.0 += &format!("C{} {} {} {} {} {}", c1.x, -c1.y, c2.x, -c2.y, to.x, -to.y);
+///     }
+///     fn close(&mut self) {
+///         self.0 += "Z";
+///     }
+/// }
+///
+/// # fn main() -> Result<(), ab_glyph::InvalidFont> {
+/// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+/// let mut path = PathData::default();
+/// font.outline_into(font.glyph_id('q'), &mut path);
+/// assert!(path.0.starts_with('M'));
+/// # Ok(()) }
+/// ```
+pub trait OutlineSink {
+    /// Starts a new contour at `to`.
+    fn move_to(&mut self, to: Point);
+
+    /// Appends a straight line to `to`.
+    fn line_to(&mut self, to: Point);
+
+    /// Appends a quadratic Bézier curve to `to` using `control`.
+    fn quad_to(&mut self, control: Point, to: Point);
+
+    /// Appends a cubic Bézier curve to `to` using `control1` at the beginning of the
+    /// curve and `control2` at the end of the curve.
+    fn cubic_to(&mut self, control1: Point, control2: Point, to: Point);
+
+    /// Closes the current contour, with a straight line back to its start if needed.
+    fn close(&mut self);
+}
+
+impl<S: OutlineSink + ?Sized> OutlineSink for &mut S {
+    #[inline]
+    fn move_to(&mut self, to: Point) {
+        (**self).move_to(to)
+    }
+
+    #[inline]
+    fn line_to(&mut self, to: Point) {
+        (**self).line_to(to)
+    }
+
+    #[inline]
+    fn quad_to(&mut self, control: Point, to: Point) {
+        (**self).quad_to(control, to)
+    }
+
+    #[inline]
+    fn cubic_to(&mut self, control1: Point, control2: Point, to: Point) {
+        (**self).cubic_to(control1, control2, to)
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        (**self).close()
+    }
+}
+
+/// Sends the curves of each `outline` contour to `sink`.
+pub(crate) fn sink_curves(outline: &Outline, sink: &mut dyn OutlineSink) {
+    for curves in outline.contour_curves() {
+        sink.move_to(curves[0].start());
+        for curve in curves {
+            match *curve {
+                OutlineCurve::Line(_, p1) => sink.line_to(p1),
+                OutlineCurve::Quad(_, p1, p2) => sink.quad_to(p1, p2),
+                OutlineCurve::Cubic(_, p1, p2, p3) => sink.cubic_to(p1, p2, p3),
+            }
+        }
+        sink.close();
+    }
+}
@@ -0,0 +1,424 @@
+//! Outline stroking.
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{
+    outlined::curves_bounds,
+    point,
+    sdf::{cross, dot, length, Edge},
+    FillRule, Outline, OutlineCurve, OutlinedGlyph, Point, PxScaleFactor, Transform,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Max direction change in radians of a curve piece approximated by a single offset curve.
+const MAX_OFFSET_PIECE_ANGLE: f32 = core::f32::consts::FRAC_PI_8;
+/// Max pieces used to offset a single curve.
+const MAX_OFFSET_PIECES: f32 = 64.0;
+/// Direction samples used to measure how much a curve turns.
+const TURN_SAMPLES: u8 = 16;
+
+/// Shape used to join stroked segments where the outline changes direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LineJoin {
+    /// Extend the outer edges to meet at a sharp point, falling back to [`LineJoin::Bevel`]
+    /// beyond the [`Stroke::miter_limit`].
+    #[default]
+    Miter,
+    /// Round corners with radius of half the stroke width.
+    Round,
+    /// Cut corners off with a straight line.
+    Bevel,
+}
+
+/// Shape used to end stroked open contours.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    /// End flat exactly at the end points.
+    #[default]
+    Butt,
+    /// End with a semicircle of radius half the stroke width.
+    Round,
+    /// End flat, extended half the stroke width past the end points.
+    Square,
+}
+
+/// Stroke style used to convert an outline into a stroked outline,
+/// see [`Outline::stroke`] & [`OutlinedGlyph::stroked`].
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// let stroke = Stroke::new(2.0).with_join(LineJoin::Round);
+/// assert_eq!(stroke.width, 2.0);
+/// assert_eq!(stroke.cap, LineCap::Butt);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    /// Full width of the stroke, centred on the outline.
+    pub width: f32,
+    /// Shape used where segments meet, default [`LineJoin::Miter`].
+    pub join: LineJoin,
+    /// Max ratio of the miter length to the stroke [`Stroke::width`] for
+    /// [`LineJoin::Miter`] joins, sharper corners are beveled. Default `4.0`.
+    pub miter_limit: f32,
+    /// Shape used at the ends of open contours, default [`LineCap::Butt`].
+    pub cap: LineCap,
+}
+
+impl Stroke {
+    /// Stroke with a given width & default join, miter limit & cap.
+    #[inline]
+    pub const fn new(width: f32) -> Self {
+        Self {
+            width,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            cap: LineCap::Butt,
+        }
+    }
+
+    /// Returns this stroke using a given [`LineJoin`].
+    #[inline]
+    pub const fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Returns this stroke using a given miter limit.
+    #[inline]
+    pub const fn with_miter_limit(mut self, miter_limit: f32) -> Self {
+        self.miter_limit = miter_limit;
+        self
+    }
+
+    /// Returns this stroke using a given [`LineCap`].
+    #[inline]
+    pub const fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+}
+
+impl Outline {
+    /// Returns the outline of this outline stroked with `stroke`, in the same units.
+    ///
+    /// Closed contours produce a ring of the stroke width & open contours a capped band.
+    /// The stroked outline may overlap itself, so should be drawn with [`FillRule::NonZero`].
+    /// Rings all wind clockwise, like glyph outer contours in TrueType fonts.
+    /// Curves are approximated by quadratic offset curves.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let outline = font.outline(font.glyph_id('q')).unwrap();
+    /// // 50 font units wide stroke
+    /// let stroked = outline.stroke(Stroke::new(50.0));
+    /// assert!(stroked.bounds.width() > outline.bounds.width());
+    /// ```
+    pub fn stroke(&self, stroke: Stroke) -> Outline {
+        let mut stroker = Stroker {
+            half_width: stroke.width / 2.0,
+            stroke,
+            curves: Vec::new(),
+            contour_starts: Vec::new(),
+        };
+        if stroker.half_width > 0.0 {
+            let mut contour = Vec::new();
+            for curves in self.contour_curves() {
+                contour.clear();
+                contour.extend(
+                    curves
+                        .iter()
+                        .filter(|curve| curve.points().any(|p| p != curve.start()))
+                        .map(|curve| Edge(*curve)),
+                );
+                stroker.stroke_contour(&contour);
+            }
+        }
+
+        Outline {
+            bounds: curves_bounds(&stroker.curves),
+            curves: stroker.curves,
+            contour_starts: stroker.contour_starts,
+        }
+    }
+}
+
+impl OutlinedGlyph {
+    /// Returns the outline of this glyph stroked with `stroke` using pixel units,
+    /// e.g. to draw hollow text or a border around the glyph fill.
+    ///
+    /// The stroke is centred on the outline so extends half the `stroke.width` outside.
+    /// The returned glyph uses [`FillRule::NonZero`] & the same flattening tolerance
+    /// & [`Self::transform`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let q = font.outline_glyph(font.glyph_id('q').with_scale(24.0)).unwrap();
+    ///
+    /// // 2px wide border
+    /// let border = q.stroked(Stroke::new(2.0).with_join(LineJoin::Round));
+    /// border.draw(|x, y, c| { /* draw pixel `(x, y)` with coverage: `c` */ });
+    /// // then draw the fill `q` over the border, offset by the `px_bounds` difference
+    /// ```
+    pub fn stroked(&self, stroke: Stroke) -> OutlinedGlyph {
+        let PxScaleFactor {
+            horizontal,
+            vertical,
+        } = self.scale_factor;
+        let mut outline = self.outline.clone();
+        outline.transform(Transform::scale(horizontal, vertical));
+
+        let scale_factor = PxScaleFactor {
+            horizontal: 1.0,
+            vertical: 1.0,
+        };
+        OutlinedGlyph::new(self.glyph().clone(), outline.stroke(stroke), scale_factor)
+            .with_fill_rule(FillRule::NonZero)
+            .with_flattening_tolerance(self.flattening_tolerance())
+            .with_transform(self.transform())
+    }
+}
+
+/// Returns `v` scaled to unit length.
+#[inline]
+fn normalize(v: Point) -> Point {
+    let len = length(v);
+    if len > 0.0 {
+        v * (1.0 / len)
+    } else {
+        v
+    }
+}
+
+/// Returns the left normal of unit direction `d`.
+#[inline]
+fn normal(d: Point) -> Point {
+    point(-d.y, d.x)
+}
+
+/// Appends stroked outline curves.
+struct Stroker {
+    stroke: Stroke,
+    half_width: f32,
+    curves: Vec<OutlineCurve>,
+    contour_starts: Vec<usize>,
+}
+
+impl Stroker {
+    /// Returns `p` offset by the stroke half width perpendicular to unit direction `d`,
+    /// to the left for `side` `1.0` or right for `-1.0`.
+    #[inline]
+    fn offset(&self, p: Point, d: Point, side: f32) -> Point {
+        p + normal(d) * (side * self.half_width)
+    }
+
+    fn stroke_contour(&mut self, contour: &[Edge]) {
+        let (Some(first), Some(last)) = (contour.first(), contour.last()) else {
+            return;
+        };
+        if first.start() == last.end() {
+            // closed contours are outlined by offset contours on each side,
+            // the right side reversed so the ring between them is filled
+            for side in [1.0, -1.0] {
+                let start = self.curves.len();
+                self.contour_starts.push(start);
+                for (i, edge) in contour.iter().enumerate() {
+                    self.offset_edge(edge, side);
+                    let next = &contour[(i + 1) % contour.len()];
+                    self.join(edge, next, side);
+                }
+                if side < 0.0 {
+                    reverse(&mut self.curves[start..]);
+                }
+            }
+        } else {
+            // open contours are outlined by a single contour along the left side,
+            // the end cap, back along the right side & the start cap
+            let mut start = self.curves.len();
+            self.contour_starts.push(start);
+            for side in [1.0, -1.0] {
+                for (i, edge) in contour.iter().enumerate() {
+                    if i > 0 {
+                        self.join(&contour[i - 1], edge, side);
+                    }
+                    self.offset_edge(edge, side);
+                }
+                if side > 0.0 {
+                    self.cap(last.end(), normalize(last.direction(1.0)));
+                    start = self.curves.len();
+                } else {
+                    reverse(&mut self.curves[start..]);
+                    self.cap(first.start(), normalize(first.direction(0.0)) * -1.0);
+                }
+            }
+        }
+    }
+
+    /// Appends a curve offset to one `side` of `edge`.
+    fn offset_edge(&mut self, edge: &Edge, side: f32) {
+        if let OutlineCurve::Line(p0, p1) = edge.0 {
+            let d = normalize(p1 - p0);
+            let line = OutlineCurve::Line(self.offset(p0, d, side), self.offset(p1, d, side));
+            self.curves.push(line);
+            return;
+        }
+
+        // split into pieces that turn a small angle, each approximated by a quad
+        let mut turn = 0.0;
+        let mut last_dir = normalize(edge.direction(0.0));
+        for i in 1..=TURN_SAMPLES {
+            let dir = normalize(edge.direction(f32::from(i) / f32::from(TURN_SAMPLES)));
+            turn += cross(last_dir, dir).atan2(dot(last_dir, dir)).abs();
+            last_dir = dir;
+        }
+        let pieces = (turn / MAX_OFFSET_PIECE_ANGLE)
+            .ceil()
+            .clamp(1.0, MAX_OFFSET_PIECES) as u32;
+
+        let mut p0 = edge.start();
+        let mut d0 = normalize(edge.direction(0.0));
+        for i in 1..=pieces {
+            let t1 = i as f32 / pieces as f32;
+            let p1 = if i == pieces {
+                edge.end()
+            } else {
+                edge.point(t1)
+            };
+            let d1 = normalize(edge.direction(t1));
+            let (o0, o1) = (self.offset(p0, d0, side), self.offset(p1, d1, side));
+
+            // control point at the intersection of the offset tangents
+            let (r, denom) = (o1 - o0, cross(d0, d1));
+            let (u, v) = if denom.abs() > 1e-6 {
+                (cross(r, d1) / denom, cross(d0, r) / denom)
+            } else {
+                (-1.0, -1.0)
+            };
+            let chord = length(r);
+            if u > 0.0 && v > 0.0 && u < 2.0 * chord && v < 2.0 * chord {
+                self.curves.push(OutlineCurve::Quad(o0, o0 + d0 * u, o1));
+            } else {
+                self.curves.push(OutlineCurve::Line(o0, o1));
+            }
+            (p0, d0) = (p1, d1);
+        }
+    }
+
+    /// Appends the join between `edge` & the following `next` on one `side`.
+    fn join(&mut self, edge: &Edge, next: &Edge, side: f32) {
+        let v = edge.end();
+        let (a, b) = (
+            normalize(edge.direction(1.0)),
+            normalize(next.direction(0.0)),
+        );
+        let (p, q) = (self.offset(v, a, side), self.offset(v, b, side));
+        if p == q {
+            return;
+        }
+        if side * cross(a, b) > 0.0 {
+            // inner side, connect through the centre which is covered by the stroke
+            self.curves.push(OutlineCurve::Line(p, v));
+            self.curves.push(OutlineCurve::Line(v, q));
+            return;
+        }
+
+        match self.stroke.join {
+            LineJoin::Miter => {
+                // miter length / stroke width = 1 / cos(θ / 2) for normals angle θ
+                let cos_half_sq = (1.0 + dot(a, b)) / 2.0;
+                let limit = self.stroke.miter_limit;
+                if cos_half_sq > 0.0 && cos_half_sq * limit * limit >= 1.0 {
+                    let miter = v
+                        + (normal(a) + normal(b)) * (side * self.half_width / (2.0 * cos_half_sq));
+                    self.curves.push(OutlineCurve::Line(p, miter));
+                    self.curves.push(OutlineCurve::Line(miter, q));
+                } else {
+                    self.curves.push(OutlineCurve::Line(p, q));
+                }
+            }
+            LineJoin::Round => self.arc(v, p, q, a),
+            LineJoin::Bevel => self.curves.push(OutlineCurve::Line(p, q)),
+        }
+    }
+
+    /// Appends the cap at the end point `v` of an open contour with unit direction `d`,
+    /// from the left side to the right side.
+    fn cap(&mut self, v: Point, d: Point) {
+        let (p, q) = (self.offset(v, d, 1.0), self.offset(v, d, -1.0));
+        match self.stroke.cap {
+            LineCap::Butt => self.curves.push(OutlineCurve::Line(p, q)),
+            LineCap::Square => {
+                let ext = d * self.half_width;
+                self.curves.push(OutlineCurve::Line(p, p + ext));
+                self.curves.push(OutlineCurve::Line(p + ext, q + ext));
+                self.curves.push(OutlineCurve::Line(q + ext, q));
+            }
+            LineCap::Round => self.arc(v, p, q, d),
+        }
+    }
+
+    /// Appends a circular arc around `centre` from `p` to `q`, passing on the side of
+    /// direction `d` if they are opposite.
+    fn arc(&mut self, centre: Point, p: Point, q: Point, d: Point) {
+        let (u, w) = (p - centre, q - centre);
+        let mut sweep = cross(u, w).atan2(dot(u, w));
+        if dot(normalize(u), normalize(w)) < -0.999_99 {
+            sweep = if cross(u, d) < 0.0 {
+                -core::f32::consts::PI
+            } else {
+                core::f32::consts::PI
+            };
+        }
+
+        // cubic arcs of at most 90°
+        let pieces = (sweep.abs() / core::f32::consts::FRAC_PI_2).ceil().max(1.0) as u32;
+        let step = sweep / pieces as f32;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let rotate = Transform::rotate(step);
+        let mut from = u;
+        for i in 1..=pieces {
+            let to = if i == pieces {
+                w
+            } else {
+                rotate.transform_point(from)
+            };
+            self.curves.push(OutlineCurve::Cubic(
+                centre + from,
+                centre + from + normal(from) * k,
+                centre + to - normal(to) * k,
+                centre + to,
+            ));
+            from = to;
+        }
+    }
+}
+
+/// Returns the signed area of closed `curves` using their control polygons,
+/// positive when winding counter-clockwise.
+pub(crate) fn signed_area(curves: &[OutlineCurve]) -> f32 {
+    let area: f32 = curves
+        .iter()
+        .flat_map(|curve| {
+            let points = curve.points();
+            points.clone().zip(points.skip(1))
+        })
+        .map(|(a, b)| cross(a, b))
+        .sum();
+    area / 2.0
+}
+
+/// Reverses the direction of contiguous `curves`.
+pub(crate) fn reverse(curves: &mut [OutlineCurve]) {
+    curves.reverse();
+    for curve in curves {
+        *curve = match *curve {
+            OutlineCurve::Line(p0, p1) => OutlineCurve::Line(p1, p0),
+            OutlineCurve::Quad(p0, p1, p2) => OutlineCurve::Quad(p2, p1, p0),
+            OutlineCurve::Cubic(p0, p1, p2, p3) => OutlineCurve::Cubic(p3, p2, p1, p0),
+        };
+    }
+}
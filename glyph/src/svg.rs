@@ -0,0 +1,195 @@
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{
+    point, sink::sink_curves, Font, Glyph, Outline, OutlineSink, OutlinedGlyph, Point,
+    PxScaleFactor, Rect, Transform,
+};
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+impl Outline {
+    /// Returns SVG path data, i.e. the `d` attribute of a `<path>`, of the outline scaled
+    /// by `scale_factor` & positioned at `position` in pixel coordinates where y points
+    /// down, like [`Outline::px_bounds`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let outline = font.outline(font.glyph_id('q')).unwrap();
+    /// let scale_factor = font.as_scaled(24.0).scale_factor();
+    ///
+    /// let d = outline.svg_path(scale_factor, point(10.0, 20.0));
+    /// assert!(d.starts_with('M'));
+    /// ```
+    pub fn svg_path(&self, scale_factor: PxScaleFactor, position: Point) -> String {
+        let to_px = Transform::scale(scale_factor.horizontal, -scale_factor.vertical)
+            .then(Transform::translate(position.x, position.y));
+        let mut d = String::new();
+        write_svg_path(&mut d, self, to_px);
+        d
+    }
+}
+
+impl OutlinedGlyph {
+    /// Returns SVG path data, i.e. the `d` attribute of a `<path>`, of the glyph at its
+    /// scale, position & [`Self::transform`] in pixel coordinates matching [`Self::px_bounds`].
+    ///
+    /// Path data does not include the [`Self::fill_rule`], so a glyph using
+    /// [`FillRule::EvenOdd`](crate::FillRule::EvenOdd) should set `fill-rule="evenodd"`
+    /// on its `<path>`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+    /// let q = font.glyph_id('q').with_scale_and_position(24.0, point(10.0, 20.0));
+    /// let q = font.outline_glyph(q).unwrap();
+    ///
+    /// let path = format!(r#"<path d="{}"/>"#, q.svg_path());
+    /// ```
+    pub fn svg_path(&self) -> String {
+        let mut d = String::new();
+        write_svg_path(&mut d, &self.outline, self.svg_transform());
+        d
+    }
+
+    /// Returns the transform from unscaled outline coordinates to absolute pixel coordinates.
+    fn svg_transform(&self) -> Transform {
+        let position = self.glyph().position;
+        Transform::scale(self.scale_factor.horizontal, -self.scale_factor.vertical)
+            .then(self.transform())
+            .then(Transform::translate(position.x, position.y))
+    }
+}
+
+/// Returns a standalone SVG document drawing the outlines of positioned `glyphs` of `font`
+/// in pixel coordinates. The document size & `viewBox` fit the glyph [`OutlinedGlyph::px_bounds`].
+///
+/// Each glyph with an outline is drawn with a `<path>`, filled using the SVG default black
+/// & nonzero fill rule, matching the default [`FillRule::NonZero`](crate::FillRule::NonZero)
+/// of [`Font::outline_glyph`].
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// # let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf")).unwrap();
+/// let glyphs: Vec<_> = "ab"
+///     .chars()
+///     .zip([0.0, 12.0])
+///     .map(|(c, x)| font.glyph_id(c).with_scale_and_position(24.0, point(x, 20.0)))
+///     .collect();
+///
+/// let svg = svg_document(&font, &glyphs);
+/// assert!(svg.starts_with("<svg"));
+/// assert_eq!(svg.matches("<path").count(), 2);
+/// ```
+pub fn svg_document<F: Font>(font: F, glyphs: &[Glyph]) -> String {
+    let outlined: Vec<_> = glyphs
+        .iter()
+        .filter_map(|glyph| font.outline_glyph(glyph.clone()))
+        .collect();
+
+    let bounds = outlined
+        .iter()
+        .map(OutlinedGlyph::px_bounds)
+        .reduce(|a, b| Rect {
+            min: point(a.min.x.min(b.min.x), a.min.y.min(b.min.y)),
+            max: point(a.max.x.max(b.max.x), a.max.y.max(b.max.y)),
+        })
+        .unwrap_or_default();
+
+    let mut svg = String::new();
+    let (x, y, w, h) = (bounds.min.x, bounds.min.y, bounds.width(), bounds.height());
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">"#
+    );
+    for glyph in &outlined {
+        svg.push_str(r#"<path d=""#);
+        write_svg_path(&mut svg, &glyph.outline, glyph.svg_transform());
+        svg.push_str("\"/>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Appends SVG path data of `outline` transformed by `to_px` to `out`.
+fn write_svg_path(out: &mut String, outline: &Outline, to_px: Transform) {
+    sink_curves(
+        outline,
+        &mut SvgPathWriter {
+            out,
+            to_px,
+            start: point(0.0, 0.0),
+            pending_line: None,
+        },
+    );
+}
+
+/// [`OutlineSink`] writing absolute SVG path commands.
+struct SvgPathWriter<'a> {
+    out: &'a mut String,
+    to_px: Transform,
+    /// Contour start.
+    start: Point,
+    /// Line written on the next command, unless closing the contour.
+    pending_line: Option<Point>,
+}
+
+impl SvgPathWriter<'_> {
+    fn command(&mut self, command: char, points: &[Point]) {
+        if let Some(to) = self.pending_line.take() {
+            self.command('L', &[to]);
+        }
+        self.out.push(command);
+        for (idx, p) in points.iter().enumerate() {
+            if idx > 0 {
+                self.out.push(' ');
+            }
+            let p = self.to_px.transform_point(*p);
+            write_number(self.out, p.x);
+            self.out.push(' ');
+            write_number(self.out, p.y);
+        }
+    }
+}
+
+impl OutlineSink for SvgPathWriter<'_> {
+    fn move_to(&mut self, to: Point) {
+        self.start = to;
+        self.command('M', &[to]);
+    }
+
+    fn line_to(&mut self, to: Point) {
+        if let Some(prev) = self.pending_line.take() {
+            self.command('L', &[prev]);
+        }
+        self.pending_line = Some(to);
+    }
+
+    fn quad_to(&mut self, control: Point, to: Point) {
+        self.command('Q', &[control, to]);
+    }
+
+    fn cubic_to(&mut self, control1: Point, control2: Point, to: Point) {
+        self.command('C', &[control1, control2, to]);
+    }
+
+    fn close(&mut self) {
+        // closing lines back to the start are implied by "Z"
+        if self.pending_line == Some(self.start) {
+            self.pending_line = None;
+        }
+        self.command('Z', &[]);
+    }
+}
+
+/// Writes `v` rounded to 3 decimal places, without trailing zeros.
+fn write_number(out: &mut String, v: f32) {
+    // adding zero avoids writing "-0"
+    let v = (v * 1000.0).round() / 1000.0 + 0.0;
+    let _ = write!(out, "{v}");
+}
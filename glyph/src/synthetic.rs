@@ -0,0 +1,302 @@
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{
+    sink::sink_curves, stroke, v2, DecorationMetrics, Font, FontName, GlyphId, GlyphSvg,
+    LineMetrics, LineMetricsMode, NameId, Os2Metrics, Outline, OutlineSink, Rect, Stroke,
+    Transform,
+};
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// `Font` wrapper synthesizing bold and/or oblique (slanted italic) glyphs from
+/// another font, e.g. for fonts that only have a regular face.
+///
+/// Bold glyph outlines are grown by the embolden strength, keeping the left & bottom
+/// edges in place, with non-zero advances increased to fit. Oblique glyph outlines are slanted
+/// by the oblique angle & reported by [`Font::italic_angle`].
+///
+/// Can be used inside a [`FontArc`](crate::FontArc).
+///
+/// # Example
+/// ```
+/// use ab_glyph::{Font, FontRef, SyntheticFont};
+///
+/// # fn main() -> Result<(), ab_glyph::InvalidFont> {
+/// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Exo2-Light.otf"))?;
+/// let bold_italic = SyntheticFont::new(font.clone())
+///     .with_embolden(1.0 / 24.0)
+///     .with_oblique_angle(-12.0);
+///
+/// let a = font.glyph_id('a');
+/// assert!(bold_italic.h_advance_unscaled(a) > font.h_advance_unscaled(a));
+/// assert_eq!(bold_italic.italic_angle(), -12.0);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SyntheticFont<F> {
+    font: F,
+    embolden: f32,
+    oblique_angle: f32,
+}
+
+impl<F: Font> SyntheticFont<F> {
+    /// Wraps a font, initially without any synthesized style.
+    #[inline]
+    pub fn new(font: F) -> Self {
+        Self {
+            font,
+            embolden: 0.0,
+            oblique_angle: 0.0,
+        }
+    }
+
+    /// Returns this font synthesizing bold glyphs, growing outlines & advances by
+    /// `strength` as a fraction of the em size. Zero advances, e.g. of combining marks,
+    /// are not grown. E.g. `1.0 / 24.0` is similar to FreeType's synthetic bold.
+    ///
+    /// Outlines cannot be thinned, so negative or NaN strengths are treated as `0.0`.
+    #[inline]
+    pub fn with_embolden(mut self, strength: f32) -> Self {
+        self.embolden = strength.max(0.0);
+        self
+    }
+
+    /// Embolden strength as a fraction of the em size, `0.0` if not synthesizing bold.
+    #[inline]
+    pub fn embolden(&self) -> f32 {
+        self.embolden
+    }
+
+    /// Returns this font synthesizing oblique glyphs, slanted by `angle` in
+    /// counter-clockwise degrees from vertical like [`Font::italic_angle`].
+    /// E.g. `-12.0` leans glyphs to the right.
+    #[inline]
+    pub fn with_oblique_angle(mut self, angle: f32) -> Self {
+        self.oblique_angle = angle;
+        self
+    }
+
+    /// Oblique angle in counter-clockwise degrees from vertical, `0.0` if not
+    /// synthesizing oblique.
+    #[inline]
+    pub fn oblique_angle(&self) -> f32 {
+        self.oblique_angle
+    }
+
+    /// Reference to the wrapped font.
+    #[inline]
+    pub fn font(&self) -> &F {
+        &self.font
+    }
+
+    /// Returns the wrapped font.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        self.font
+    }
+
+    /// Embolden strength in font units.
+    #[inline]
+    fn embolden_unscaled(&self) -> f32 {
+        if self.embolden == 0.0 {
+            return 0.0;
+        }
+        let em = self
+            .font
+            .units_per_em()
+            .unwrap_or_else(|| self.font.height_unscaled());
+        self.embolden * em
+    }
+
+    /// Returns `advance` grown by the embolden strength, unless zero like FreeType.
+    #[inline]
+    fn emboldened_advance(&self, advance: f32) -> f32 {
+        if advance == 0.0 {
+            return advance;
+        }
+        advance + self.embolden_unscaled()
+    }
+}
+
+impl<F: Font> Font for SyntheticFont<F> {
+    #[inline]
+    fn units_per_em(&self) -> Option<f32> {
+        self.font.units_per_em()
+    }
+
+    #[inline]
+    fn ascent_unscaled(&self) -> f32 {
+        self.font.ascent_unscaled()
+    }
+
+    #[inline]
+    fn descent_unscaled(&self) -> f32 {
+        self.font.descent_unscaled()
+    }
+
+    #[inline]
+    fn line_gap_unscaled(&self) -> f32 {
+        self.font.line_gap_unscaled()
+    }
+
+    #[inline]
+    fn line_metrics_unscaled(&self, mode: LineMetricsMode) -> LineMetrics {
+        self.font.line_metrics_unscaled(mode)
+    }
+
+    #[inline]
+    fn os2_metrics(&self) -> Option<Os2Metrics> {
+        self.font.os2_metrics()
+    }
+
+    #[inline]
+    fn underline_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        self.font.underline_metrics_unscaled()
+    }
+
+    #[inline]
+    fn strikeout_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+        self.font.strikeout_metrics_unscaled()
+    }
+
+    #[inline]
+    fn italic_angle(&self) -> f32 {
+        self.font.italic_angle() + self.oblique_angle
+    }
+
+    #[inline]
+    fn glyph_id(&self, c: char) -> GlyphId {
+        self.font.glyph_id(c)
+    }
+
+    #[inline]
+    fn h_advance_unscaled(&self, id: GlyphId) -> f32 {
+        self.emboldened_advance(self.font.h_advance_unscaled(id))
+    }
+
+    #[inline]
+    fn h_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
+        self.font.h_side_bearing_unscaled(id)
+    }
+
+    #[inline]
+    fn v_advance_unscaled(&self, id: GlyphId) -> f32 {
+        self.emboldened_advance(self.font.v_advance_unscaled(id))
+    }
+
+    #[inline]
+    fn v_side_bearing_unscaled(&self, id: GlyphId) -> f32 {
+        self.font.v_side_bearing_unscaled(id)
+    }
+
+    #[inline]
+    fn kern_unscaled(&self, first: GlyphId, second: GlyphId) -> f32 {
+        self.font.kern_unscaled(first, second)
+    }
+
+    fn outline(&self, id: GlyphId) -> Option<Outline> {
+        let mut outline = self.font.outline(id)?;
+        let mut transform = Transform::IDENTITY;
+
+        let embolden = self.embolden_unscaled();
+        if embolden > 0.0 {
+            // union with the outline stroke, moved to keep the left & bottom edges in place
+            let mut stroked = outline.stroke(Stroke::new(embolden));
+            if stroke::signed_area(&outline.curves) > 0.0 {
+                // stroke rings wind clockwise so must be reversed to add to
+                // counter-clockwise fills, e.g. cff outlines
+                let ends = stroked
+                    .contour_starts
+                    .iter()
+                    .skip(1)
+                    .copied()
+                    .chain([stroked.curves.len()]);
+                for (start, end) in stroked.contour_starts.iter().copied().zip(ends) {
+                    stroke::reverse(&mut stroked.curves[start..end]);
+                }
+            }
+            if outline.contour_starts.first() != Some(&0) && !outline.curves.is_empty() {
+                // curves before the first recorded start form a contour
+                outline.contour_starts.insert(0, 0);
+            }
+            let offset = outline.curves.len();
+            outline
+                .contour_starts
+                .extend(stroked.contour_starts.iter().map(|start| start + offset));
+            outline.curves.extend(stroked.curves);
+            transform = Transform::translate(embolden / 2.0, embolden / 2.0);
+        }
+        if self.oblique_angle != 0.0 {
+            let skew = Transform {
+                xy: (-self.oblique_angle).to_radians().tan(),
+                ..Transform::IDENTITY
+            };
+            transform = transform.then(skew);
+        }
+
+        if transform != Transform::IDENTITY {
+            outline.transform(transform);
+        }
+        Some(outline)
+    }
+
+    fn outline_into(&self, id: GlyphId, sink: &mut dyn OutlineSink) -> Option<Rect> {
+        if self.embolden == 0.0 && self.oblique_angle == 0.0 {
+            return self.font.outline_into(id, sink);
+        }
+        let outline = self.outline(id)?;
+        sink_curves(&outline, sink);
+        Some(outline.bounds)
+    }
+
+    #[inline]
+    fn names(&self) -> Vec<FontName> {
+        self.font.names()
+    }
+
+    #[inline]
+    fn name(&self, name_id: NameId) -> Option<String> {
+        self.font.name(name_id)
+    }
+
+    #[inline]
+    fn localized_name(&self, name_id: NameId, language_id: u16) -> Option<String> {
+        self.font.localized_name(name_id, language_id)
+    }
+
+    #[inline]
+    fn family_name(&self) -> Option<String> {
+        self.font.family_name()
+    }
+
+    #[inline]
+    fn subfamily_name(&self) -> Option<String> {
+        self.font.subfamily_name()
+    }
+
+    #[inline]
+    fn glyph_count(&self) -> usize {
+        self.font.glyph_count()
+    }
+
+    #[inline]
+    fn codepoint_ids(&self) -> crate::CodepointIdIter<'_> {
+        self.font.codepoint_ids()
+    }
+
+    #[inline]
+    fn glyph_raster_image2(&self, id: GlyphId, size: u16) -> Option<v2::GlyphImage<'_>> {
+        self.font.glyph_raster_image2(id, size)
+    }
+
+    #[inline]
+    fn glyph_svg_image(&self, id: GlyphId) -> Option<GlyphSvg<'_>> {
+        self.font.glyph_svg_image(id)
+    }
+
+    #[inline]
+    fn font_data(&self) -> &[u8] {
+        self.font.font_data()
+    }
+}
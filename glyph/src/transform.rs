@@ -0,0 +1,111 @@
+#[cfg(all(feature = "libm", not(feature = "std")))]
+use crate::nostd_float::FloatExt;
+use crate::{point, Point};
+
+/// A 2D affine transformation, e.g. rotation, skew, scale, mirroring & translation.
+///
+/// Maps a point `(x, y)` to `(xx * x + xy * y + tx, yx * x + yy * y + ty)`.
+///
+/// # Example
+/// ```
+/// # use ab_glyph::*;
+/// // rotate 90° then move 10px right
+/// let transform = Transform::rotate(core::f32::consts::FRAC_PI_2).then(Transform::translate(10.0, 0.0));
+///
+/// let p = transform.transform_point(point(1.0, 0.0));
+/// assert!((p.x - 10.0).abs() < 1e-6);
+/// assert!((p.y - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub xx: f32,
+    pub yx: f32,
+    pub xy: f32,
+    pub yy: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Transform {
+    /// The transform that leaves points unchanged.
+    pub const IDENTITY: Self = Self::scale(1.0, 1.0);
+
+    /// Translation by `(x, y)`.
+    #[inline]
+    pub const fn translate(x: f32, y: f32) -> Self {
+        Self {
+            tx: x,
+            ty: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Scale by `x` horizontally & `y` vertically. Negative values mirror.
+    #[inline]
+    pub const fn scale(x: f32, y: f32) -> Self {
+        Self {
+            xx: x,
+            yx: 0.0,
+            xy: 0.0,
+            yy: y,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Rotation by `radians` around the origin, from the positive x axis towards the
+    /// positive y axis. I.e. clockwise in pixel coordinates where y points down.
+    #[inline]
+    pub fn rotate(radians: f32) -> Self {
+        let (sin, cos) = (radians.sin(), radians.cos());
+        Self {
+            xx: cos,
+            yx: sin,
+            xy: -sin,
+            yy: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Skew by angles `x_radians` along the x axis & `y_radians` along the y axis.
+    ///
+    /// E.g. `Transform::skew(-0.2, 0.0)` slants upright text to the right in pixel
+    /// coordinates where y points down.
+    #[inline]
+    pub fn skew(x_radians: f32, y_radians: f32) -> Self {
+        Self {
+            xy: x_radians.tan(),
+            yx: y_radians.tan(),
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the transform applying `self` then `next`.
+    #[inline]
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            xx: next.xx * self.xx + next.xy * self.yx,
+            yx: next.yx * self.xx + next.yy * self.yx,
+            xy: next.xx * self.xy + next.xy * self.yy,
+            yy: next.yx * self.xy + next.yy * self.yy,
+            tx: next.xx * self.tx + next.xy * self.ty + next.tx,
+            ty: next.yx * self.tx + next.yy * self.ty + next.ty,
+        }
+    }
+
+    /// Returns the transformed point.
+    #[inline]
+    pub fn transform_point(&self, p: Point) -> Point {
+        point(
+            self.xx * p.x + self.xy * p.y + self.tx,
+            self.yx * p.x + self.yy * p.y + self.ty,
+        )
+    }
+}
+
+impl Default for Transform {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
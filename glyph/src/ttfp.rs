@@ -3,8 +3,15 @@ mod outliner;
 #[cfg(feature = "variable-fonts")]
 mod variable;
 
-use crate::{point, v2, Font, GlyphId, GlyphImageFormat, GlyphSvg, InvalidFont, Outline, Rect};
+use crate::{
+    metrics::select_line_metrics, name::select_language, point, v2, DecorationMetrics, Font,
+    FontError, FontName, FsSelection, GlyphId, GlyphImageFormat, GlyphSvg, InvalidFont,
+    LineMetrics, LineMetricsMode, NameId, Os2Metrics, Outline, OutlineSink, Rect,
+};
 use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use core::fmt;
@@ -62,7 +69,7 @@ impl<'font> FontRef<'font> {
     /// Creates an `FontRef` from byte-slice.
     ///
     /// You can set index for font collections. For simple fonts use `0` or
-    /// [`FontRef::try_from_slice`].
+    /// [`FontRef::try_from_slice`]. See [`FontRef::parse`] for a detailed error.
     ///
     /// # Example
     /// ```
@@ -74,9 +81,120 @@ impl<'font> FontRef<'font> {
     /// ```
     #[inline]
     pub fn try_from_slice_and_index(data: &'font [u8], index: u32) -> Result<Self, InvalidFont> {
-        Ok(Self(ttfp::PreParsedSubtables::from(
-            ttfp::Face::parse(data, index).map_err(|_| InvalidFont)?,
-        )))
+        Ok(Self::parse(data, index)?)
+    }
+
+    /// Creates an `FontRef` from byte-slice & face index, like
+    /// [`FontRef::try_from_slice_and_index`] with a detailed [`FontError`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// match FontRef::parse(include_bytes!("../../dev/fonts/Exo2-Light.otf"), 0) {
+    ///     Ok(font) => assert_eq!(font.glyph_id('s'), GlyphId(56)),
+    ///     Err(FontError::FaceIndexOutOfBounds { face_count, .. }) => {
+    ///         eprintln!("font has {face_count} faces")
+    ///     }
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    pub fn parse(data: &'font [u8], index: u32) -> Result<Self, FontError> {
+        let face = ttfp::Face::parse(data, index)
+            .map_err(|err| font_error(err, index, face_count(data)))?;
+        Ok(Self(ttfp::PreParsedSubtables::from(face)))
+    }
+}
+
+/// Owned font data shared by multiple fonts.
+#[cfg(target_has_atomic = "ptr")]
+pub(crate) type SharedData = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// Font face parsed from shared data, see [`FontArc::try_from_data`](crate::FontArc::try_from_data).
+///
+/// Clones share the data, which each clone keeps alive.
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Clone)]
+pub(crate) struct SharedFont(
+    // declared first so is dropped before the data it references
+    ttfp::PreParsedSubtables<'static, ttfp::Face<'static>>,
+    // keeps the face data alive
+    #[allow(dead_code)] SharedData,
+);
+
+#[cfg(target_has_atomic = "ptr")]
+impl SharedFont {
+    /// Parses face `index` of `data`.
+    pub(crate) fn parse(data: SharedData, index: u32) -> Result<Self, FontError> {
+        let bytes: &[u8] = (*data).as_ref();
+        // Safety: The face only references data owned by the `Arc`, which does not move
+        // & lives until after the face is dropped. Data borrowed from a shared reference
+        // cannot be mutated while any shared reference exists. Face references are never
+        // exposed as `'static`.
+        let static_bytes: &'static [u8] = unsafe { &*(bytes as *const [u8]) };
+        let face = ttfp::Face::parse(static_bytes, index)
+            .map_err(|err| font_error(err, index, face_count(static_bytes)))?;
+        Ok(Self(ttfp::PreParsedSubtables::from(face), data))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl From<FontRef<'static>> for SharedFont {
+    /// Shares the face, keeping variations.
+    #[inline]
+    fn from(font: FontRef<'static>) -> Self {
+        let data: &'static [u8] = font.0.face.raw_face().data;
+        Self(font.0, Arc::new(data))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl From<FontVec> for SharedFont {
+    /// Shares a copy of the face referencing the `font` data, keeping variations.
+    fn from(font: FontVec) -> Self {
+        // Safety: The face only references the heap data owned by `font`, which does not
+        // move when `font` moves into the `Arc` & lives until after the face is dropped.
+        // The shared `font` is never mutated.
+        let face = unsafe {
+            core::mem::transmute::<ttfp::Face<'_>, ttfp::Face<'static>>(
+                font.0.as_face_ref().clone(),
+            )
+        };
+        Self(
+            ttfp::PreParsedSubtables::from(face),
+            Arc::new(FontVecData(font)),
+        )
+    }
+}
+
+/// [`FontVec`] font data kept alive by a [`SharedFont`].
+#[cfg(target_has_atomic = "ptr")]
+struct FontVecData(FontVec);
+
+#[cfg(target_has_atomic = "ptr")]
+impl AsRef<[u8]> for FontVecData {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Returns the number of faces in font `data`, `1` unless the data is a font collection.
+#[inline]
+pub(crate) fn face_count(data: &[u8]) -> u32 {
+    ttfp::fonts_in_collection(data).unwrap_or(1)
+}
+
+/// Converts a ttf-parser error parsing face `index` of font data with `face_count` faces.
+fn font_error(err: ttfp::FaceParsingError, index: u32, face_count: u32) -> FontError {
+    match err {
+        ttfp::FaceParsingError::MalformedFont => FontError::Malformed,
+        ttfp::FaceParsingError::UnknownMagic => FontError::UnknownMagic,
+        ttfp::FaceParsingError::FaceIndexOutOfBounds => {
+            FontError::FaceIndexOutOfBounds { index, face_count }
+        }
+        ttfp::FaceParsingError::NoHeadTable => FontError::RequiredTable { tag: *b"head" },
+        ttfp::FaceParsingError::NoHheaTable => FontError::RequiredTable { tag: *b"hhea" },
+        ttfp::FaceParsingError::NoMaxpTable => FontError::RequiredTable { tag: *b"maxp" },
     }
 }
 
@@ -126,7 +244,7 @@ impl FontVec {
     /// Creates an `FontVec` from owned data.
     ///
     /// You can set index for font collections. For simple fonts use `0` or
-    /// [`FontVec::try_from_vec`].
+    /// [`FontVec::try_from_vec`]. See [`FontVec::parse`] for a detailed error.
     ///
     /// # Example
     /// ```
@@ -138,9 +256,23 @@ impl FontVec {
     /// ```
     #[inline]
     pub fn try_from_vec_and_index(data: Vec<u8>, index: u32) -> Result<Self, InvalidFont> {
-        Ok(Self(ttfp::PreParsedSubtables::from(
-            ttfp::OwnedFace::from_vec(data, index).map_err(|_| InvalidFont)?,
-        )))
+        Ok(Self::parse(data, index)?)
+    }
+
+    /// Creates an `FontVec` from owned data & face index, like
+    /// [`FontVec::try_from_vec_and_index`] with a detailed [`FontError`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ab_glyph::*;
+    /// let err = FontVec::parse(b"wOFF".to_vec(), 0).unwrap_err();
+    /// assert_eq!(err, FontError::UnknownMagic);
+    /// ```
+    pub fn parse(data: Vec<u8>, index: u32) -> Result<Self, FontError> {
+        let face_count = face_count(&data);
+        let face = ttfp::OwnedFace::from_vec(data, index)
+            .map_err(|err| font_error(err, index, face_count))?;
+        Ok(Self(ttfp::PreParsedSubtables::from(face)))
     }
 
     /// Extracts a slice containing the data passed into e.g. [`FontVec::try_from_vec`].
@@ -203,6 +335,62 @@ macro_rules! impl_font {
                 self.0.as_face_ref().line_gap().into()
             }
 
+            fn line_metrics_unscaled(&self, mode: LineMetricsMode) -> LineMetrics {
+                let face = self.0.as_face_ref();
+                let auto = LineMetrics {
+                    ascent: face.ascender().into(),
+                    descent: face.descender().into(),
+                    line_gap: face.line_gap().into(),
+                };
+                let hhea = face.tables().hhea;
+                let hhea = LineMetrics {
+                    ascent: hhea.ascender.into(),
+                    descent: hhea.descender.into(),
+                    line_gap: hhea.line_gap.into(),
+                };
+                select_line_metrics(mode, auto, hhea, self.os2_metrics())
+            }
+
+            fn os2_metrics(&self) -> Option<Os2Metrics> {
+                let face = self.0.as_face_ref();
+                let os2 = face.tables().os2?;
+                let fs_selection = face
+                    .raw_face()
+                    .table(ttfp::Tag::from_bytes(b"OS/2"))
+                    .and_then(|data| Some(u16::from_be_bytes([*data.get(62)?, *data.get(63)?])))
+                    .unwrap_or(0);
+                Some(Os2Metrics {
+                    typo_ascent: face.typographic_ascender()?.into(),
+                    typo_descent: face.typographic_descender()?.into(),
+                    typo_line_gap: face.typographic_line_gap()?.into(),
+                    win_ascent: os2.windows_ascender().into(),
+                    win_descent: os2.windows_descender().into(),
+                    x_height: face.x_height().map(f32::from),
+                    cap_height: face.capital_height().map(f32::from),
+                    weight_class: os2.weight().to_number(),
+                    width_class: os2.width().to_number(),
+                    fs_selection: FsSelection(fs_selection),
+                })
+            }
+
+            #[inline]
+            fn underline_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+                let metrics = self.0.as_face_ref().underline_metrics()?;
+                Some(DecorationMetrics {
+                    position: metrics.position.into(),
+                    thickness: metrics.thickness.into(),
+                })
+            }
+
+            #[inline]
+            fn strikeout_metrics_unscaled(&self) -> Option<DecorationMetrics> {
+                let metrics = self.0.as_face_ref().strikeout_metrics()?;
+                Some(DecorationMetrics {
+                    position: metrics.position.into(),
+                    thickness: metrics.thickness.into(),
+                })
+            }
+
             #[inline]
             fn italic_angle(&self) -> f32 {
                 self.0.as_face_ref().italic_angle()
@@ -262,27 +450,22 @@ macro_rules! impl_font {
 
             fn outline(&self, id: GlyphId) -> Option<Outline> {
                 let mut outliner = outliner::OutlineCurveBuilder::default();
+                let bounds = outline_glyph(self.0.as_face_ref(), id, &mut outliner);
+                let bounds = outline_bounds(bounds)?;
+                let (curves, contour_starts) = outliner.take_outline();
+                Some(Outline {
+                    bounds,
+                    curves,
+                    contour_starts,
+                })
+            }
 
-                let ttfp::Rect {
-                    x_min,
-                    x_max,
-                    y_min,
-                    y_max,
-                } = self
-                    .0
-                    .as_face_ref()
-                    .outline_glyph(id.into(), &mut outliner)
-                    // invalid bounds are treated as having no outline
-                    .filter(|b| b.x_min < b.x_max && b.y_min < b.y_max)?;
-
-                let curves = outliner.take_outline();
-
-                let bounds = Rect {
-                    min: point(x_min.into(), y_max.into()),
-                    max: point(x_max.into(), y_min.into()),
-                };
-
-                Some(Outline { bounds, curves })
+            fn outline_into(&self, id: GlyphId, sink: &mut dyn OutlineSink) -> Option<Rect> {
+                let face = self.0.as_face_ref();
+                // check the bounds first so nothing is sent for glyphs without an outline
+                let bounds = outline_bounds(face.glyph_bounding_box(id.into()))?;
+                outline_glyph(face, id, sink);
+                Some(bounds)
             }
 
             #[inline]
@@ -362,9 +545,84 @@ macro_rules! impl_font {
             fn font_data(&self) -> &[u8] {
                 self.0.as_face_ref().raw_face().data
             }
+
+            fn names(&self) -> Vec<FontName> {
+                self.0
+                    .as_face_ref()
+                    .names()
+                    .into_iter()
+                    .filter(ttfp::name::Name::is_unicode)
+                    .map(|name| FontName {
+                        name_id: NameId(name.name_id),
+                        language_id: name_language(&name),
+                        value: decode_name(&name),
+                    })
+                    .collect()
+            }
+
+            #[inline]
+            fn localized_name(&self, name_id: NameId, language_id: u16) -> Option<String> {
+                face_name(self.0.as_face_ref(), name_id.0, language_id)
+            }
         }
     };
 }
 
 impl_font!(FontRef<'_>);
 impl_font!(FontVec);
+#[cfg(target_has_atomic = "ptr")]
+impl_font!(SharedFont);
+
+/// Sends the glyph `id` outline to `sink`, returning the outlined bounds.
+fn outline_glyph(
+    face: &ttfp::Face<'_>,
+    id: GlyphId,
+    sink: &mut dyn OutlineSink,
+) -> Option<ttfp::Rect> {
+    let mut builder = outliner::SinkBuilder::new(sink);
+    let bounds = face.outline_glyph(id.into(), &mut builder);
+    builder.finish();
+    bounds
+}
+
+/// Converts outlined glyph `bounds`, treating invalid bounds as having no outline.
+fn outline_bounds(bounds: Option<ttfp::Rect>) -> Option<Rect> {
+    let ttfp::Rect {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    } = bounds.filter(|b| b.x_min < b.x_max && b.y_min < b.y_max)?;
+
+    Some(Rect {
+        min: point(x_min.into(), y_max.into()),
+        max: point(x_max.into(), y_min.into()),
+    })
+}
+
+/// Returns the unicode `name_id` entry of the face name table in `language_id`,
+/// see [`Font::localized_name`].
+pub(crate) fn face_name(face: &ttfp::Face<'_>, name_id: u16, language_id: u16) -> Option<String> {
+    let names = face
+        .names()
+        .into_iter()
+        .filter(|name| name.name_id == name_id && name.is_unicode());
+    let name = select_language(names, name_language, language_id)?;
+    Some(decode_name(&name))
+}
+
+/// Returns the Windows language ID of a name, if known.
+fn name_language(name: &ttfp::name::Name<'_>) -> Option<u16> {
+    (name.platform_id == ttfp::PlatformId::Windows).then_some(name.language_id)
+}
+
+/// Decodes a unicode, UTF-16BE, name.
+fn decode_name(name: &ttfp::name::Name<'_>) -> String {
+    let utf16 = name
+        .name
+        .chunks_exact(2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]));
+    core::char::decode_utf16(utf16)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::{point, OutlineCurve, Point};
+use crate::{point, OutlineCurve, OutlineSink, Point};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
@@ -7,52 +7,49 @@ pub(crate) struct OutlineCurveBuilder {
     last: Point,
     last_move: Option<Point>,
     outline: Vec<OutlineCurve>,
+    contour_starts: Vec<usize>,
 }
 
 impl OutlineCurveBuilder {
+    /// Returns the outline curves & the indices starting each contour.
     #[inline]
-    pub(crate) fn take_outline(mut self) -> Vec<OutlineCurve> {
-        // some font glyphs implicitly close, e.g. Cantarell-VF.otf
-        owned_ttf_parser::OutlineBuilder::close(&mut self);
-        self.outline
+    pub(crate) fn take_outline(self) -> (Vec<OutlineCurve>, Vec<usize>) {
+        (self.outline, self.contour_starts)
     }
 }
 
-impl owned_ttf_parser::OutlineBuilder for OutlineCurveBuilder {
+impl OutlineSink for OutlineCurveBuilder {
     #[inline]
-    fn move_to(&mut self, x: f32, y: f32) {
-        // eprintln!("M {x} {y}");
-        self.last = point(x, y);
+    fn move_to(&mut self, to: Point) {
+        // eprintln!("M {} {}", to.x, to.y);
+        self.last = to;
         self.last_move = Some(self.last);
+        if self.contour_starts.last() != Some(&self.outline.len()) {
+            self.contour_starts.push(self.outline.len());
+        }
     }
 
     #[inline]
-    fn line_to(&mut self, x1: f32, y1: f32) {
-        // eprintln!("L {x1} {y1}");
-        let p1 = point(x1, y1);
-        self.outline.push(OutlineCurve::Line(self.last, p1));
-        self.last = p1;
+    fn line_to(&mut self, to: Point) {
+        // eprintln!("L {} {}", to.x, to.y);
+        self.outline.push(OutlineCurve::Line(self.last, to));
+        self.last = to;
     }
 
     #[inline]
-    fn quad_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
-        // eprintln!("Q {x1} {y1}");
-        let p1 = point(x1, y1);
-        let p2 = point(x2, y2);
-        self.outline.push(OutlineCurve::Quad(self.last, p1, p2));
-        self.last = p2;
+    fn quad_to(&mut self, control: Point, to: Point) {
+        // eprintln!("Q {} {}", control.x, control.y);
+        self.outline
+            .push(OutlineCurve::Quad(self.last, control, to));
+        self.last = to;
     }
 
     #[inline]
-    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32) {
-        // eprintln!("C {x1} {y1} {x3} {y3}");
-        let p1 = point(x1, y1);
-        let p2 = point(x2, y2);
-        let p3 = point(x3, y3);
-
+    fn cubic_to(&mut self, control1: Point, control2: Point, to: Point) {
+        // eprintln!("C {} {} {} {}", control1.x, control1.y, to.x, to.y);
         self.outline
-            .push(OutlineCurve::Cubic(self.last, p1, p2, p3));
-        self.last = p3;
+            .push(OutlineCurve::Cubic(self.last, control1, control2, to));
+        self.last = to;
     }
 
     #[inline]
@@ -63,3 +60,56 @@ impl owned_ttf_parser::OutlineBuilder for OutlineCurveBuilder {
         }
     }
 }
+
+/// Forwards ttf-parser outlines to an [`OutlineSink`] ensuring each contour is closed.
+pub(crate) struct SinkBuilder<'a> {
+    sink: &'a mut dyn OutlineSink,
+    open: bool,
+}
+
+impl<'a> SinkBuilder<'a> {
+    #[inline]
+    pub(crate) fn new(sink: &'a mut dyn OutlineSink) -> Self {
+        Self { sink, open: false }
+    }
+
+    /// Closes the last contour if the font did not,
+    /// e.g. some font glyphs implicitly close like Cantarell-VF.otf.
+    #[inline]
+    pub(crate) fn finish(mut self) {
+        owned_ttf_parser::OutlineBuilder::close(&mut self);
+    }
+}
+
+impl owned_ttf_parser::OutlineBuilder for SinkBuilder<'_> {
+    #[inline]
+    fn move_to(&mut self, x: f32, y: f32) {
+        owned_ttf_parser::OutlineBuilder::close(self);
+        self.sink.move_to(point(x, y));
+        self.open = true;
+    }
+
+    #[inline]
+    fn line_to(&mut self, x1: f32, y1: f32) {
+        self.sink.line_to(point(x1, y1));
+    }
+
+    #[inline]
+    fn quad_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        self.sink.quad_to(point(x1, y1), point(x2, y2));
+    }
+
+    #[inline]
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32) {
+        self.sink
+            .cubic_to(point(x1, y1), point(x2, y2), point(x3, y3));
+    }
+
+    #[inline]
+    fn close(&mut self) {
+        if self.open {
+            self.sink.close();
+            self.open = false;
+        }
+    }
+}
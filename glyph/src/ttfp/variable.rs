@@ -1,4 +1,6 @@
-use crate::{FontRef, FontVec, VariableFont, VariationAxis};
+#[cfg(target_has_atomic = "ptr")]
+use super::SharedFont;
+use crate::{name::ENGLISH_US, FontRef, FontVec, NamedInstance, VariableFont, VariationAxis};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use owned_ttf_parser::{self as ttfp, AsFaceRef, FaceMut};
@@ -22,6 +24,10 @@ impl VariableFont for FontRef<'_> {
     fn variations(&self) -> Vec<VariationAxis> {
         variations(self.0.as_face_ref())
     }
+
+    fn named_instances(&self) -> Vec<NamedInstance> {
+        named_instances(self.0.as_face_ref()).unwrap_or_default()
+    }
 }
 
 impl VariableFont for FontVec {
@@ -34,30 +40,100 @@ impl VariableFont for FontVec {
     fn variations(&self) -> Vec<VariationAxis> {
         variations(self.0.as_face_ref())
     }
+
+    fn named_instances(&self) -> Vec<NamedInstance> {
+        named_instances(self.0.as_face_ref()).unwrap_or_default()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl VariableFont for SharedFont {
+    fn set_variation(&mut self, axis: &[u8; 4], value: f32) -> bool {
+        let tag = ttfp::Tag::from_bytes(axis);
+        let exists = self
+            .0
+            .as_face_ref()
+            .variation_axes()
+            .into_iter()
+            .any(|axis| axis.tag == tag);
+        if exists {
+            self.0.set_variation(tag, value);
+        }
+        exists
+    }
+
+    fn variations(&self) -> Vec<VariationAxis> {
+        variations(self.0.as_face_ref())
+    }
+
+    fn named_instances(&self) -> Vec<NamedInstance> {
+        named_instances(self.0.as_face_ref()).unwrap_or_default()
+    }
 }
 
 fn variations(face: &ttfp::Face<'_>) -> Vec<VariationAxis> {
     face.variation_axes()
         .into_iter()
-        .map(|axis| {
-            #[cfg(feature = "std")]
-            let name = face.names().into_iter().find_map(|n| {
-                if n.name_id == axis.name_id {
-                    n.to_string()
-                } else {
-                    None
-                }
-            });
-            #[cfg(not(feature = "std"))]
-            let name = None;
-            VariationAxis {
-                tag: axis.tag.to_bytes(),
-                name,
-                min_value: axis.min_value,
-                default_value: axis.def_value,
-                max_value: axis.max_value,
-                hidden: axis.hidden,
-            }
+        .map(|axis| VariationAxis {
+            tag: axis.tag.to_bytes(),
+            name: super::face_name(face, axis.name_id, ENGLISH_US),
+            min_value: axis.min_value,
+            default_value: axis.def_value,
+            max_value: axis.max_value,
+            hidden: axis.hidden,
+        })
+        .collect()
+}
+
+/// Parses the fvar table instance records, which ttf-parser does not provide.
+fn named_instances(face: &ttfp::Face<'_>) -> Option<Vec<NamedInstance>> {
+    let data = face.raw_face().table(ttfp::Tag::from_bytes(b"fvar"))?;
+    let read_u16 = |offset: usize| {
+        Some(u16::from_be_bytes([
+            *data.get(offset)?,
+            *data.get(offset + 1)?,
+        ]))
+    };
+
+    let axes_offset = usize::from(read_u16(4)?);
+    let axis_count = usize::from(read_u16(8)?);
+    let axis_size = usize::from(read_u16(10)?);
+    let instance_count = usize::from(read_u16(12)?);
+    let instance_size = usize::from(read_u16(14)?);
+    let tags: Vec<_> = face
+        .variation_axes()
+        .into_iter()
+        .map(|axis| axis.tag.to_bytes())
+        .collect();
+    if tags.len() != axis_count {
+        return None;
+    }
+
+    let instances_offset = axes_offset + axis_count * axis_size;
+    let name = |name_id: u16| super::face_name(face, name_id, ENGLISH_US);
+    (0..instance_count)
+        .map(|idx| {
+            let offset = instances_offset + idx * instance_size;
+            let coordinates = tags
+                .iter()
+                .enumerate()
+                .map(|(axis, tag)| {
+                    let at = offset + 4 + axis * 4;
+                    let fixed = i32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?);
+                    Some((*tag, fixed as f32 / 65536.0))
+                })
+                .collect::<Option<_>>()?;
+            let postscript_name = match instance_size >= 6 + axis_count * 4 {
+                true => read_u16(offset + 4 + axis_count * 4)
+                    .filter(|id| *id != 0xffff)
+                    .and_then(name),
+                false => None,
+            };
+            Some(NamedInstance {
+                name: name(read_u16(offset)?),
+                postscript_name,
+                coordinates,
+            })
         })
         .collect()
 }
@@ -47,6 +47,57 @@ pub trait VariableFont {
     /// # Ok(()) }
     /// ```
     fn variations(&self) -> Vec<VariationAxis>;
+
+    /// Returns the named instances, preset styles e.g. "Bold", defined by the font designer.
+    ///
+    /// Returns empty, the default, for fonts without named instances.
+    ///
+    /// # Example
+    /// ```
+    /// use ab_glyph::{FontRef, VariableFont};
+    ///
+    /// # fn main() -> Result<(), ab_glyph::InvalidFont> {
+    /// let font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Cantarell-VF.otf"))?;
+    /// let instances = font.named_instances();
+    ///
+    /// let bold = &instances[3];
+    /// assert_eq!(bold.name.as_deref(), Some("Bold"));
+    /// assert_eq!(bold.coordinates, [(*b"wght", 700.0)]);
+    /// # Ok(()) }
+    /// ```
+    fn named_instances(&self) -> Vec<NamedInstance> {
+        Vec::new()
+    }
+
+    /// Sets all variation axis coordinates to those of named instance `index`,
+    /// see [`VariableFont::named_instances`].
+    ///
+    /// Returns false if there is no such named instance.
+    ///
+    /// # Example
+    /// ```
+    /// use ab_glyph::{FontRef, VariableFont};
+    ///
+    /// # fn main() -> Result<(), ab_glyph::InvalidFont> {
+    /// let mut font = FontRef::try_from_slice(include_bytes!("../../dev/fonts/Cantarell-VF.otf"))?;
+    ///
+    /// let bold = font
+    ///     .named_instances()
+    ///     .iter()
+    ///     .position(|instance| instance.name.as_deref() == Some("Bold"))
+    ///     .unwrap();
+    /// assert!(font.set_named_instance(bold));
+    /// # Ok(()) }
+    /// ```
+    fn set_named_instance(&mut self, index: usize) -> bool {
+        let Some(instance) = self.named_instances().into_iter().nth(index) else {
+            return false;
+        };
+        for (tag, value) in &instance.coordinates {
+            self.set_variation(tag, *value);
+        }
+        true
+    }
 }
 
 #[non_exhaustive]
@@ -65,3 +116,15 @@ pub struct VariationAxis {
     /// Whether the axis should be exposed directly in user interfaces.
     pub hidden: bool,
 }
+
+/// A named instance of a variable font, i.e. a preset style defined by its axis coordinates.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedInstance {
+    /// Unicode subfamily name, e.g. "Bold" or "Condensed Light".
+    pub name: Option<String>,
+    /// Unicode PostScript name, if defined.
+    pub postscript_name: Option<String>,
+    /// Coordinate values for each variation axis tag.
+    pub coordinates: Vec<([u8; 4], f32)>,
+}
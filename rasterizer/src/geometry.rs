@@ -1,6 +1,3 @@
-#[cfg(all(feature = "libm", not(feature = "std")))]
-use crate::nostd_float::FloatExt;
-
 /// An (x, y) coordinate.
 ///
 /// # Example
@@ -20,14 +17,6 @@ impl core::fmt::Debug for Point {
     }
 }
 
-impl Point {
-    #[inline]
-    pub(crate) fn distance_to(self, other: Point) -> f32 {
-        let d = other - self;
-        (d.x * d.x + d.y * d.y).sqrt()
-    }
-}
-
 /// [`Point`] constructor.
 ///
 /// # Example
@@ -80,6 +69,23 @@ impl core::ops::Add for Point {
     }
 }
 
+impl core::ops::Mul<f32> for Point {
+    type Output = Point;
+    /// Multiply x & y by rhs.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// let p1 = point(1.0, 2.0) * 1.5;
+    ///
+    /// assert!((p1.x - 1.5).abs() <= f32::EPSILON);
+    /// assert!((p1.y - 3.0).abs() <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: f32) -> Point {
+        point(self.x * rhs, self.y * rhs)
+    }
+}
+
 impl core::ops::AddAssign for Point {
     /// ```
     /// # use ab_glyph_rasterizer::*;
@@ -135,14 +141,3 @@ impl<F: Into<f32>> From<[F; 2]> for Point {
         point(x.into(), y.into())
     }
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn distance_to() {
-        let distance = point(0.0, 0.0).distance_to(point(3.0, 4.0));
-        assert!((distance - 5.0).abs() <= f32::EPSILON);
-    }
-}
@@ -0,0 +1,404 @@
+//! Subpixel LCD coverage filtering.
+use crate::Rasterizer;
+
+/// Physical order of the red, green & blue subpixels of LCD panel pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SubpixelLayout {
+    /// Horizontal red, green, blue subpixels. The most common layout.
+    #[default]
+    Rgb,
+    /// Horizontal blue, green, red subpixels.
+    Bgr,
+    /// Vertical red, green, blue subpixels, top to bottom.
+    VRgb,
+    /// Vertical blue, green, red subpixels, top to bottom.
+    VBgr,
+}
+
+impl SubpixelLayout {
+    /// Returns `true` for vertical layouts, where subpixels are stacked top to bottom.
+    #[inline]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::VRgb | Self::VBgr)
+    }
+
+    /// Returns the subpixel index of each `[r, g, b]` channel within a pixel.
+    #[inline]
+    fn channel_subpixels(self) -> [usize; 3] {
+        match self {
+            Self::Rgb | Self::VRgb => [0, 1, 2],
+            Self::Bgr | Self::VBgr => [2, 1, 0],
+        }
+    }
+}
+
+/// 5-tap FIR filter applied across subpixel coverage to reduce colour fringing.
+///
+/// Weights apply to the subpixels `-2..=2` relative to each output subpixel
+/// and should usually sum to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LcdFilter {
+    weights: [f32; 5],
+}
+
+impl LcdFilter {
+    /// FreeType-style default filter, reduces colour fringes with a slight blur.
+    pub const DEFAULT: Self = Self::new([
+        8.0 / 256.0,
+        77.0 / 256.0,
+        86.0 / 256.0,
+        77.0 / 256.0,
+        8.0 / 256.0,
+    ]);
+    /// FreeType-style light filter, sharper than [`LcdFilter::DEFAULT`] with more colour fringing.
+    pub const LIGHT: Self = Self::new([0.0, 85.0 / 256.0, 86.0 / 256.0, 85.0 / 256.0, 0.0]);
+    /// No filtering, each channel uses its own subpixel coverage.
+    pub const NONE: Self = Self::new([0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    /// Creates a filter with custom weights for the subpixels `-2..=2`.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::LcdFilter;
+    /// let filter = LcdFilter::new([0.1, 0.2, 0.4, 0.2, 0.1]);
+    /// ```
+    #[inline]
+    pub const fn new(weights: [f32; 5]) -> Self {
+        Self { weights }
+    }
+
+    /// Returns the filter weights for the subpixels `-2..=2`.
+    #[inline]
+    pub fn weights(&self) -> [f32; 5] {
+        self.weights
+    }
+
+    /// Filters subpixel `idx` of `coverage`, treating out of range subpixels as uncovered.
+    #[inline]
+    fn apply(&self, idx: usize, coverage: impl Fn(usize) -> Option<f32>) -> f32 {
+        let mut sum = 0.0;
+        for (k, w) in self.weights.iter().enumerate() {
+            if *w != 0.0 {
+                if let Some(c) = (idx + k).checked_sub(2).and_then(&coverage) {
+                    sum += w * c;
+                }
+            }
+        }
+        sum.min(1.0)
+    }
+}
+
+impl Default for LcdFilter {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Filter taps either side of a subpixel.
+const TAPS: usize = 2;
+/// Subpixels accumulated at a time along rows, also the column width of vertical strips.
+const CHUNK: usize = 48;
+/// Subpixel rows used to filter a pixel row of a vertical layout.
+const WINDOW_ROWS: usize = 3 + 2 * TAPS;
+
+impl<A: AsRef<[f32]> + AsMut<[f32]>> Rasterizer<A> {
+    /// Run a callback for each LCD pixel `(x, y)` with filtered `[r, g, b]` coverage.
+    ///
+    /// The rasterizer should have been drawn at 3x resolution in the direction of the
+    /// `layout` subpixels, i.e. 3x width for horizontal layouts, 3x height for vertical.
+    /// Each 3 subpixels form an output pixel, so output dimensions are
+    /// `(width / 3, height)` for horizontal layouts & `(width, height / 3)` for vertical.
+    ///
+    /// Does not allocate & uses SIMD accumulation where available, see
+    /// [`Rasterizer::accumulate_into`].
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// # let (width, height) = (4, 4);
+    /// // draw at 3x horizontal resolution
+    /// let mut rasterizer = Rasterizer::new(width * 3, height);
+    /// # rasterizer.draw_line(point(0.0, 0.48), point(1.22, 0.48));
+    /// rasterizer.for_each_lcd_pixel_2d(SubpixelLayout::Rgb, LcdFilter::DEFAULT, |x, y, [r, g, b]| {
+    ///     // ...
+    /// });
+    /// ```
+    pub fn for_each_lcd_pixel_2d<O: FnMut(u32, u32, [f32; 3])>(
+        &self,
+        layout: SubpixelLayout,
+        filter: LcdFilter,
+        px_fn: O,
+    ) {
+        let channels = layout.channel_subpixels();
+        if layout.is_vertical() {
+            self.for_each_vertical_lcd_pixel(channels, filter, px_fn);
+        } else {
+            self.for_each_horizontal_lcd_pixel(channels, filter, px_fn);
+        }
+    }
+
+    /// Filters each row, accumulating subpixels a chunk at a time into a sliding window.
+    fn for_each_horizontal_lcd_pixel<O: FnMut(u32, u32, [f32; 3])>(
+        &self,
+        channels: [usize; 3],
+        filter: LcdFilter,
+        mut px_fn: O,
+    ) {
+        let (width, height) = self.dimensions();
+        let px_width = width / 3;
+        if px_width == 0 {
+            return;
+        }
+
+        // `window[i]` holds the coverage of subpixel `i + skip - TAPS` of the row,
+        // zero outside the row
+        let mut window = [0.0; CHUNK + 2 * TAPS];
+        let mut acc = 0.0;
+        for (y, mut row) in self.a()[..width * height].chunks_exact(width).enumerate() {
+            window[..TAPS].fill(0.0);
+            let (mut len, mut skip, mut x) = (TAPS, 0, 0);
+            while x < px_width {
+                let n = (window.len() - len).min(row.len());
+                acc = self.accumulate_cells(&row[..n], &mut window[len..len + n], acc);
+                row = &row[n..];
+                len += n;
+                if row.is_empty() {
+                    window[len..].fill(0.0);
+                    len = window.len();
+                }
+
+                // filter pixels with all subpixel taps in the window
+                while x < px_width && 3 * x + 3 + 2 * TAPS <= len + skip {
+                    let rgb = channels
+                        .map(|c| filter.apply(3 * x + c + TAPS - skip, |i| Some(window[i])));
+                    px_fn(x as u32, y as u32, rgb);
+                    x += 1;
+                }
+
+                // drop subpixels before the taps of the next pixel
+                let drop = 3 * x - skip;
+                window.copy_within(drop..len, 0);
+                len -= drop;
+                skip += drop;
+            }
+
+            // continue accumulation over the rest of the row
+            for rest in row.chunks(window.len()) {
+                acc = self.accumulate_cells(rest, &mut window[..rest.len()], acc);
+            }
+        }
+    }
+
+    /// Filters columns in strips, accumulating each row of the strip into a sliding
+    /// window of subpixel rows.
+    fn for_each_vertical_lcd_pixel<O: FnMut(u32, u32, [f32; 3])>(
+        &self,
+        channels: [usize; 3],
+        filter: LcdFilter,
+        mut px_fn: O,
+    ) {
+        let (width, height) = self.dimensions();
+        let grid = &self.a()[..width * height];
+
+        // `window[r * CHUNK..][..strip width]` holds the strip coverage of subpixel
+        // row `3 * y + r - TAPS`, zero outside the grid
+        let mut window = [0.0; WINDOW_ROWS * CHUNK];
+        for x0 in (0..width).step_by(CHUNK) {
+            let x1 = (x0 + CHUNK).min(width);
+            let mut rows = grid.chunks_exact(width);
+            // accumulation at the start of the next row
+            let mut acc = 0.0;
+
+            window[..TAPS * CHUNK].fill(0.0);
+            let mut len = TAPS;
+            for y in 0..height / 3 {
+                while len < WINDOW_ROWS {
+                    let strip = &mut window[len * CHUNK..][..x1 - x0];
+                    match rows.next() {
+                        Some(row) => {
+                            let before = acc + row[..x0].iter().sum::<f32>();
+                            let after = self.accumulate_cells(&row[x0..x1], strip, before);
+                            acc = after + row[x1..].iter().sum::<f32>();
+                        }
+                        None => strip.fill(0.0),
+                    }
+                    len += 1;
+                }
+
+                for x in 0..x1 - x0 {
+                    let rgb =
+                        channels.map(|c| filter.apply(c + TAPS, |r| Some(window[r * CHUNK + x])));
+                    px_fn((x0 + x) as u32, y as u32, rgb);
+                }
+
+                window.copy_within(3 * CHUNK.., 0);
+                len -= 3;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    /// Returns lcd pixels of a rasterizer drawn with a rect covering
+    /// subpixels `x0..x1` & rows `y0..y1`.
+    fn lcd_rect(
+        (w, h): (usize, usize),
+        (x0, y0, x1, y1): (f32, f32, f32, f32),
+        layout: SubpixelLayout,
+        filter: LcdFilter,
+    ) -> Vec<[f32; 3]> {
+        let mut rasterizer = Rasterizer::new(w, h);
+        rasterizer.draw_line(point(x0, y0), point(x1, y0));
+        rasterizer.draw_line(point(x1, y0), point(x1, y1));
+        rasterizer.draw_line(point(x1, y1), point(x0, y1));
+        rasterizer.draw_line(point(x0, y1), point(x0, y0));
+
+        let mut out = vec![];
+        rasterizer.for_each_lcd_pixel_2d(layout, filter, |_, _, rgb| out.push(rgb));
+        out
+    }
+
+    /// Returns lcd pixels by filtering the [`Rasterizer::for_each_pixel`] coverage of
+    /// the whole grid.
+    fn whole_grid_lcd_pixels(
+        rasterizer: &Rasterizer,
+        layout: SubpixelLayout,
+        filter: LcdFilter,
+    ) -> Vec<(u32, u32, [f32; 3])> {
+        let (width, height) = rasterizer.dimensions();
+        let mut coverage = vec![0.0; width * height];
+        rasterizer.for_each_pixel(|idx, c| coverage[idx] = c);
+        let subpixel =
+            |x: usize, y: usize| (x < width && y < height).then(|| coverage[y * width + x]);
+        let channels = layout.channel_subpixels();
+
+        let mut out = vec![];
+        if layout.is_vertical() {
+            for y in 0..height / 3 {
+                for x in 0..width {
+                    let rgb = channels.map(|c| filter.apply(3 * y + c, |sy| subpixel(x, sy)));
+                    out.push((x as u32, y as u32, rgb));
+                }
+            }
+        } else {
+            for y in 0..height {
+                for x in 0..width / 3 {
+                    let rgb = channels.map(|c| filter.apply(3 * x + c, |sx| subpixel(sx, y)));
+                    out.push((x as u32, y as u32, rgb));
+                }
+            }
+        }
+        out
+    }
+
+    /// Rows & strips spanning multiple chunks should match filtering the whole grid.
+    #[test]
+    fn chunked_eq_whole_grid() {
+        for (layout, (w, h)) in [
+            (SubpixelLayout::Rgb, (CHUNK * 3 + 7, 5)),
+            (SubpixelLayout::VBgr, (CHUNK * 2 + 5, 3 * 7 + 2)),
+        ] {
+            let mut rasterizer = Rasterizer::new(w, h);
+            let (wf, hf) = (w as f32, h as f32);
+            let star = [
+                point(0.3, hf * 0.4),
+                point(wf * 0.8, 0.2),
+                point(wf * 0.55, hf - 0.3),
+                point(wf * 0.45, 0.6),
+                point(wf - 0.1, hf * 0.9),
+                point(0.3, hf * 0.4),
+            ];
+            // covering the grid edges
+            let border = [
+                point(wf - 2.5, 0.0),
+                point(wf, 0.0),
+                point(wf, hf),
+                point(wf - 2.5, hf),
+                point(wf - 2.5, 0.0),
+            ];
+            for pair in star.windows(2).chain(border.windows(2)) {
+                rasterizer.draw_line(pair[0], pair[1]);
+            }
+
+            let mut chunked = vec![];
+            rasterizer.for_each_lcd_pixel_2d(layout, LcdFilter::DEFAULT, |x, y, rgb| {
+                chunked.push((x, y, rgb))
+            });
+            chunked.sort_by_key(|(x, y, _)| (*y, *x));
+
+            let expected = whole_grid_lcd_pixels(&rasterizer, layout, LcdFilter::DEFAULT);
+            assert_eq!(chunked.len(), expected.len());
+            assert!(expected
+                .iter()
+                .any(|(.., rgb)| rgb.iter().any(|c| *c > 0.5)));
+            for ((x, y, rgb), (ex, ey, expected_rgb)) in chunked.iter().zip(&expected) {
+                assert_eq!((x, y), (ex, ey));
+                for (c, e) in rgb.iter().zip(expected_rgb) {
+                    assert!(
+                        (c - e).abs() < 1e-4,
+                        "{layout:?} ({x}, {y}) {rgb:?} != {expected_rgb:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unfiltered_subpixels() {
+        // covers the blue subpixel of pixel 0 & red subpixel of pixel 1
+        let rgb = lcd_rect(
+            (6, 1),
+            (2.0, 0.0, 4.0, 1.0),
+            SubpixelLayout::Rgb,
+            LcdFilter::NONE,
+        );
+        assert_eq!(rgb, [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]);
+
+        let bgr = lcd_rect(
+            (6, 1),
+            (2.0, 0.0, 4.0, 1.0),
+            SubpixelLayout::Bgr,
+            LcdFilter::NONE,
+        );
+        assert_eq!(bgr, [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+
+        let vrgb = lcd_rect(
+            (1, 6),
+            (0.0, 2.0, 1.0, 4.0),
+            SubpixelLayout::VRgb,
+            LcdFilter::NONE,
+        );
+        assert_eq!(vrgb, [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn default_filter_spreads_coverage() {
+        // single green subpixel covered
+        let rgb = lcd_rect(
+            (9, 1),
+            (4.0, 0.0, 5.0, 1.0),
+            SubpixelLayout::Rgb,
+            LcdFilter::DEFAULT,
+        );
+        let [w0, w1, w2, ..] = LcdFilter::DEFAULT.weights();
+        assert_eq!(rgb, [[0.0, 0.0, w0], [w1, w2, w1], [w0, 0.0, 0.0]]);
+        let total: f32 = rgb.iter().flatten().sum();
+        assert!((total - 1.0).abs() < 1e-3, "{total}");
+    }
+
+    #[test]
+    fn full_coverage_stays_full() {
+        for filter in [LcdFilter::DEFAULT, LcdFilter::LIGHT, LcdFilter::NONE] {
+            let rgb = lcd_rect((15, 2), (0.0, 0.0, 15.0, 2.0), SubpixelLayout::Rgb, filter);
+            // inner pixels are not affected by filtering at the edges
+            for px in &rgb[1..4] {
+                for c in px {
+                    assert!((c - 1.0).abs() < 1e-2, "{filter:?} {rgb:?}");
+                }
+            }
+        }
+    }
+}
@@ -19,7 +19,7 @@
 //! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 #[macro_use]
 extern crate alloc;
 
@@ -30,7 +30,9 @@ mod nostd_float;
 compile_error!("You need to activate either the `std` or `libm` feature.");
 
 mod geometry;
+mod lcd;
 mod raster;
 
 pub use geometry::{point, Point};
-pub use raster::Rasterizer;
+pub use lcd::{LcdFilter, SubpixelLayout};
+pub use raster::{CoveragePixel, FillRule, Rasterizer};
@@ -18,21 +18,101 @@
 // Cubic bezier drawing adapted from stb_truetype: https://github.com/nothings/stb
 #[cfg(all(feature = "libm", not(feature = "std")))]
 use crate::nostd_float::FloatExt;
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
-use crate::geometry::{lerp, Point};
+use crate::geometry::{lerp, point, Point};
 
-type DrawLineFn = unsafe fn(&mut Rasterizer, Point, Point);
+type DrawLineFn = unsafe fn(&mut Grid<'_>, Point, Point);
+/// Writes the running sum of `src` onto `acc`, as coverage, into `dst` returning the final sum.
+type AccumulateFn = unsafe fn(src: &[f32], dst: &mut [f32], acc: f32, fill_rule: FillRule) -> f32;
+
+/// Rule determining pixel coverage from the accumulated outline winding.
+///
+/// ```
+/// use ab_glyph_rasterizer::{FillRule, Rasterizer};
+/// let mut rasterizer = Rasterizer::new(14, 38);
+/// assert_eq!(rasterizer.fill_rule(), FillRule::NonZero);
+///
+/// rasterizer.set_fill_rule(FillRule::EvenOdd);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Areas inside any outline are covered, including where outlines overlap.
+    ///
+    /// This is the rule used by TrueType & OpenType glyphs.
+    #[default]
+    NonZero,
+    /// Areas inside an odd number of outlines are covered, so overlapping
+    /// outlines cut holes in each other.
+    EvenOdd,
+}
+
+/// Pixel formats that [`Rasterizer::write_coverage`] can write.
+///
+/// Implemented for `u8` & `u16`, scaling coverage to the full integer range with rounding,
+/// and `f32` using coverage values as-is.
+pub trait CoveragePixel: Copy {
+    /// Converts `0.0..=1.0` pixel coverage into this format.
+    fn from_coverage(coverage: f32) -> Self;
+}
+
+impl CoveragePixel for u8 {
+    #[inline]
+    fn from_coverage(coverage: f32) -> Self {
+        // note: saturating cast, + 0.5 rounds the non-negative value
+        (coverage * 255.0 + 0.5) as u8
+    }
+}
+
+impl CoveragePixel for u16 {
+    #[inline]
+    fn from_coverage(coverage: f32) -> Self {
+        (coverage * 65535.0 + 0.5) as u16
+    }
+}
+
+impl CoveragePixel for f32 {
+    #[inline]
+    fn from_coverage(coverage: f32) -> Self {
+        coverage
+    }
+}
+
+impl FillRule {
+    /// Returns `0.0..=1.0` coverage for an accumulated winding value.
+    #[inline]
+    fn coverage(self, acc: f32) -> f32 {
+        match self {
+            Self::NonZero => acc.abs().min(1.0),
+            Self::EvenOdd => {
+                let acc = acc.abs() % 2.0;
+                1.0 - (1.0 - acc).abs()
+            }
+        }
+    }
+}
+
+/// Max number of lines used to draw a single cubic curve.
+const MAX_CUBIC_LINES: f32 = 65536.0;
 
 /// Coverage rasterizer for lines, quadratic & cubic beziers.
-pub struct Rasterizer {
+///
+/// Draws onto an accumulation buffer `A`, by default an allocated `Vec<f32>`.
+/// Caller-supplied buffers, like `&mut [f32]`, can be used to avoid allocation,
+/// see [`Rasterizer::with_buffer`]. Without the `alloc` feature there is no default
+/// so the buffer type must be specified.
+pub struct Rasterizer<#[cfg(feature = "alloc")] A = Vec<f32>, #[cfg(not(feature = "alloc"))] A> {
     width: usize,
     height: usize,
-    a: Vec<f32>,
+    a: A,
+    fill_rule: FillRule,
+    flattening_tolerance: f32,
     draw_line_fn: DrawLineFn,
+    accumulate_fn: AccumulateFn,
 }
 
+#[cfg(feature = "alloc")]
 impl Rasterizer {
     /// Allocates a new rasterizer that can draw onto a `width` x `height` alpha grid.
     ///
@@ -44,14 +124,17 @@ impl Rasterizer {
         Self {
             width,
             height,
-            a: vec![0.0; width * height + 4],
+            a: vec![0.0; Self::buffer_len(width, height)],
+            fill_rule: FillRule::default(),
+            flattening_tolerance: Self::DEFAULT_FLATTENING_TOLERANCE,
             draw_line_fn: optimal_draw_line_fn(),
+            accumulate_fn: optimal_accumulate_fn(),
         }
     }
 
     /// Resets the rasterizer to an empty `width` x `height` alpha grid. This method behaves as if
     /// the Rasterizer were re-created, with the advantage of not allocating if the total number of
-    /// pixels of the grid does not increase.
+    /// pixels of the grid does not increase. The [`FillRule`] & flattening tolerance are retained.
     ///
     /// ```
     /// # use ab_glyph_rasterizer::Rasterizer;
@@ -63,7 +146,73 @@ impl Rasterizer {
         self.width = width;
         self.height = height;
         self.a.clear();
-        self.a.resize(width * height + 4, 0.0);
+        self.a.resize(Self::buffer_len(width, height), 0.0);
+    }
+}
+
+impl<A> Rasterizer<A> {
+    /// Default max distance in pixels between curves & the lines used to draw them,
+    /// see [`Rasterizer::set_flattening_tolerance`].
+    pub const DEFAULT_FLATTENING_TOLERANCE: f32 = 0.144;
+
+    /// Returns the minimum buffer length required by [`Rasterizer::with_buffer`]
+    /// to draw onto a `width` x `height` alpha grid.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::Rasterizer;
+    /// assert_eq!(Rasterizer::<&mut [f32]>::buffer_len(14, 38), 14 * 38 + 4);
+    /// ```
+    pub const fn buffer_len(width: usize, height: usize) -> usize {
+        width * height + 4
+    }
+}
+
+impl<A: AsRef<[f32]> + AsMut<[f32]>> Rasterizer<A> {
+    /// Creates a new rasterizer that can draw onto a `width` x `height` alpha grid
+    /// using a caller-supplied accumulation `buffer`, which will be cleared.
+    ///
+    /// Does not allocate so is available without the `alloc` feature, e.g. for use with
+    /// a static scratch buffer in no_std environments.
+    ///
+    /// # Panics
+    /// Panics if the buffer length is less than [`Rasterizer::buffer_len`].
+    ///
+    /// ```
+    /// use ab_glyph_rasterizer::Rasterizer;
+    /// let mut buffer = [0.0; Rasterizer::<&mut [f32]>::buffer_len(14, 38)];
+    /// let mut rasterizer = Rasterizer::with_buffer(14, 38, &mut buffer[..]);
+    /// ```
+    pub fn with_buffer(width: usize, height: usize, buffer: A) -> Self {
+        let len = Self::buffer_len(width, height);
+        assert!(
+            buffer.as_ref().len() >= len,
+            "buffer len {} < {len} required for {width}x{height}",
+            buffer.as_ref().len()
+        );
+        let mut rasterizer = Self {
+            width,
+            height,
+            a: buffer,
+            fill_rule: FillRule::default(),
+            flattening_tolerance: Self::DEFAULT_FLATTENING_TOLERANCE,
+            draw_line_fn: optimal_draw_line_fn(),
+            accumulate_fn: optimal_accumulate_fn(),
+        };
+        rasterizer.clear();
+        rasterizer
+    }
+
+    /// Returns the accumulation grid, the buffer limited to the current dimensions.
+    #[inline]
+    pub(crate) fn a(&self) -> &[f32] {
+        &self.a.as_ref()[..self.width * self.height + 4]
+    }
+
+    /// Writes the coverage of grid cells `src`, accumulated onto `acc`, into `dst` returning
+    /// the final sum. Uses SIMD accumulation where available.
+    #[inline]
+    pub(crate) fn accumulate_cells(&self, src: &[f32], dst: &mut [f32], acc: f32) -> f32 {
+        unsafe { (self.accumulate_fn)(src, dst, acc, self.fill_rule) }
     }
 
     /// Clears the rasterizer. This method behaves as if the Rasterizer were re-created with the same
@@ -75,11 +224,67 @@ impl Rasterizer {
     /// rasterizer.clear();
     /// ```
     pub fn clear(&mut self) {
-        for px in &mut self.a {
+        let len = self.width * self.height + 4;
+        for px in &mut self.a.as_mut()[..len] {
             *px = 0.0;
         }
     }
 
+    /// Sets the rule used to determine pixel coverage, [`FillRule::NonZero`] by default.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// # let mut rasterizer = Rasterizer::new(14, 38);
+    /// rasterizer.set_fill_rule(FillRule::EvenOdd);
+    /// assert_eq!(rasterizer.fill_rule(), FillRule::EvenOdd);
+    /// ```
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.fill_rule = fill_rule;
+    }
+
+    /// Returns the rule used to determine pixel coverage.
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Sets the curve flattening tolerance, the approximate max distance in pixels
+    /// between drawn quadratic & cubic curves and the lines used to draw them.
+    /// Default [`Rasterizer::DEFAULT_FLATTENING_TOLERANCE`].
+    ///
+    /// Lower values draw more accurate curves, e.g. to avoid faceting in very large text,
+    /// at the cost of drawing more lines. Higher values are faster but less accurate,
+    /// e.g. for small text.
+    ///
+    /// # Panics
+    /// Panics if the tolerance is not positive & finite.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// # let mut rasterizer = Rasterizer::new(14, 38);
+    /// rasterizer.set_flattening_tolerance(0.05);
+    /// assert_eq!(rasterizer.flattening_tolerance(), 0.05);
+    /// ```
+    pub fn set_flattening_tolerance(&mut self, tolerance: f32) {
+        assert!(
+            tolerance > 0.0 && tolerance.is_finite(),
+            "invalid flattening tolerance {tolerance}"
+        );
+        self.flattening_tolerance = tolerance;
+    }
+
+    /// Returns the curve flattening tolerance in pixels.
+    pub fn flattening_tolerance(&self) -> f32 {
+        self.flattening_tolerance
+    }
+
+    /// Returns the square of the flattening tolerance relative to the default, which
+    /// the quadratic flattening thresholds are scaled by.
+    #[inline]
+    fn flattening_scale_squared(&self) -> f32 {
+        let scale = self.flattening_tolerance / Self::DEFAULT_FLATTENING_TOLERANCE;
+        scale * scale
+    }
+
     /// Returns the dimensions the rasterizer was built to draw to.
     ///
     /// ```
@@ -99,75 +304,14 @@ impl Rasterizer {
     /// rasterizer.draw_line(point(0.0, 0.48), point(1.22, 0.48));
     /// ```
     pub fn draw_line(&mut self, p0: Point, p1: Point) {
-        unsafe { (self.draw_line_fn)(self, p0, p1) }
-    }
-
-    #[inline(always)] // must inline for simd versions
-    fn draw_line_scalar(&mut self, p0: Point, p1: Point) {
-        /// Does `self.a[$lhs] += $rhs` except if $lhs is oob `continue` instead of panic.
-        macro_rules! add_assign_a_idx {
-            ($lhs:expr, $rhs:expr) => {
-                match self.a.get_mut($lhs) {
-                    Some(v) => *v += $rhs,
-                    None => continue,
-                };
-            };
-        }
-
-        if (p0.y - p1.y).abs() <= f32::EPSILON {
-            return;
-        }
-        let (dir, p0, p1) = if p0.y < p1.y {
-            (1.0, p0, p1)
-        } else {
-            (-1.0, p1, p0)
+        let draw_line_fn = self.draw_line_fn;
+        let len = self.width * self.height + 4;
+        let mut grid = Grid {
+            width: self.width,
+            height: self.height,
+            a: &mut self.a.as_mut()[..len],
         };
-        let dxdy = (p1.x - p0.x) / (p1.y - p0.y);
-        let mut x = p0.x;
-        let y0 = p0.y as usize; // note: implicit max of 0 because usize
-        if p0.y < 0.0 {
-            x -= p0.y * dxdy;
-        }
-        for y in y0..self.height.min(p1.y.ceil() as usize) {
-            let linestart = y * self.width;
-            let dy = ((y + 1) as f32).min(p1.y) - (y as f32).max(p0.y);
-            let xnext = x + dxdy * dy;
-            let d = dy * dir;
-            let (x0, x1) = if x < xnext { (x, xnext) } else { (xnext, x) };
-            let x0floor = x0.floor();
-            let x0i = x0floor as i32;
-            let x1ceil = x1.ceil();
-            let x1i = x1ceil as i32;
-            let linestart_x0i = linestart as isize + x0i as isize;
-            if linestart_x0i < 0 {
-                continue;
-            }
-            if x1i <= x0i + 1 {
-                let xmf = 0.5 * (x + xnext) - x0floor;
-                add_assign_a_idx!(linestart_x0i as usize, d - d * xmf);
-                add_assign_a_idx!(linestart_x0i as usize + 1, d * xmf);
-            } else {
-                let s = (x1 - x0).recip();
-                let x0f = x0 - x0floor;
-                let a0 = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
-                let x1f = x1 - x1ceil + 1.0;
-                let am = 0.5 * s * x1f * x1f;
-                add_assign_a_idx!(linestart_x0i as usize, d * a0);
-                if x1i == x0i + 2 {
-                    add_assign_a_idx!(linestart_x0i as usize + 1, d * (1.0 - a0 - am));
-                } else {
-                    let a1 = s * (1.5 - x0f);
-                    add_assign_a_idx!(linestart_x0i as usize + 1, d * (a1 - a0));
-                    for xi in x0i + 2..x1i - 1 {
-                        add_assign_a_idx!(linestart + xi as usize, d * s);
-                    }
-                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
-                    add_assign_a_idx!(linestart + (x1i - 1) as usize, d * (1.0 - a2 - am));
-                }
-                add_assign_a_idx!(linestart + x1i as usize, d * am);
-            }
-            x = xnext;
-        }
+        unsafe { draw_line_fn(&mut grid, p0, p1) }
     }
 
     /// Adds a quadratic Bézier curve from `p0` to `p2` to the outline using `p1` as the control.
@@ -181,11 +325,13 @@ impl Rasterizer {
         let devx = p0.x - 2.0 * p1.x + p2.x;
         let devy = p0.y - 2.0 * p1.y + p2.y;
         let devsq = devx * devx + devy * devy;
-        if devsq < 0.333 {
+        // max error of `n` evenly spaced lines is ~`sqrt(devsq) / (4 * n^2)` pixels
+        let scale_sq = self.flattening_scale_squared();
+        if devsq < 0.333 * scale_sq {
             self.draw_line(p0, p2);
             return;
         }
-        let tol = 3.0;
+        let tol = 3.0 / scale_sq;
         let n = 1 + (tol * devsq).sqrt().sqrt().floor() as usize;
         let mut p = p0;
         let nrecip = (n as f32).recip();
@@ -213,41 +359,42 @@ impl Rasterizer {
     /// );
     /// ```
     pub fn draw_cubic(&mut self, p0: Point, p1: Point, p2: Point, p3: Point) {
-        self.tessellate_cubic(p0, p1, p2, p3, 0);
-    }
-
-    // stb_truetype style cubic approximation by lines.
-    fn tessellate_cubic(&mut self, p0: Point, p1: Point, p2: Point, p3: Point, n: u8) {
-        // ...I'm not sure either ¯\_(ツ)_/¯
-        const OBJSPACE_FLATNESS: f32 = 0.35;
-        const OBJSPACE_FLATNESS_SQUARED: f32 = OBJSPACE_FLATNESS * OBJSPACE_FLATNESS;
-        const MAX_RECURSION_DEPTH: u8 = 16;
-
-        let longlen = p0.distance_to(p1) + p1.distance_to(p2) + p2.distance_to(p3);
-        let shortlen = p0.distance_to(p3);
-        let flatness_squared = longlen * longlen - shortlen * shortlen;
-
-        if n < MAX_RECURSION_DEPTH && flatness_squared > OBJSPACE_FLATNESS_SQUARED {
-            let p01 = lerp(0.5, p0, p1);
-            let p12 = lerp(0.5, p1, p2);
-            let p23 = lerp(0.5, p2, p3);
-
-            let pa = lerp(0.5, p01, p12);
-            let pb = lerp(0.5, p12, p23);
+        // Max error of `n` evenly spaced lines is bounded by `max|B''(t)| / (8 * n^2)`,
+        // where `B''` is linear so is max at either end `6 * max(|dev0|, |dev1|)`.
+        // Lines target half the tolerance, keeping default accuracy comparable to the
+        // previous adaptive subdivision.
+        let dev0 = point(p0.x - 2.0 * p1.x + p2.x, p0.y - 2.0 * p1.y + p2.y);
+        let dev1 = point(p1.x - 2.0 * p2.x + p3.x, p1.y - 2.0 * p2.y + p3.y);
+        let devsq = (dev0.x * dev0.x + dev0.y * dev0.y).max(dev1.x * dev1.x + dev1.y * dev1.y);
+        let tol = 1.5 / self.flattening_tolerance;
+        let n = (tol * devsq.sqrt()).sqrt().ceil();
+        if n.is_nan() || n <= 1.0 {
+            self.draw_line(p0, p3);
+            return;
+        }
+        let n = n.min(MAX_CUBIC_LINES) as usize;
 
-            let mp = lerp(0.5, pa, pb);
+        // power basis coefficients, B(t) = p0 + t * (c1 + t * (c2 + t * c3))
+        let c1 = (p1 - p0) * 3.0;
+        let c2 = dev0 * 3.0;
+        let c3 = p3 - p0 + (p1 - p2) * 3.0;
 
-            self.tessellate_cubic(p0, p01, pa, mp, n + 1);
-            self.tessellate_cubic(mp, pb, p23, p3, n + 1);
-        } else {
-            self.draw_line(p0, p3);
+        let mut p = p0;
+        let nrecip = (n as f32).recip();
+        for i in 1..n {
+            let t = i as f32 * nrecip;
+            let pn = p0 + (c1 + (c2 + c3 * t) * t) * t;
+            self.draw_line(p, pn);
+            p = pn;
         }
+        self.draw_line(p, p3);
     }
 
     /// Run a callback for each pixel `index` & `alpha`, with indices in `0..width * height`.
     ///
     /// An `alpha` coverage value of `0.0` means the pixel is not covered at all by the glyph,
-    /// whereas a value of `1.0` (or greater) means the pixel is totally covered.
+    /// whereas a value of `1.0` means the pixel is totally covered, according to the
+    /// [`FillRule`].
     ///
     /// ```
     /// # use ab_glyph_rasterizer::*;
@@ -259,16 +406,91 @@ impl Rasterizer {
     /// });
     /// ```
     pub fn for_each_pixel<O: FnMut(usize, f32)>(&self, mut px_fn: O) {
+        let fill_rule = self.fill_rule;
         let mut acc = 0.0;
-        self.a[..self.width * self.height]
+        self.a()[..self.width * self.height]
             .iter()
             .enumerate()
             .for_each(|(idx, c)| {
                 acc += c;
-                px_fn(idx, acc.abs());
+                px_fn(idx, fill_rule.coverage(acc));
             });
     }
 
+    /// Writes pixel alpha coverage into `target`, with indices in `0..width * height`.
+    ///
+    /// Faster alternative to [`Rasterizer::for_each_pixel`] using SIMD accumulation where
+    /// available. Alpha values may differ from `for_each_pixel` by float rounding error
+    /// as the summation order differs.
+    ///
+    /// # Panics
+    /// Panics if `target.len()` is less than `width * height`.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// # let (width, height) = (9, 8);
+    /// # let mut rasterizer = Rasterizer::new(width, height);
+    /// # rasterizer.draw_line(point(0.0, 0.48), point(1.22, 0.48));
+    /// let mut coverage = vec![0.0; width * height];
+    /// rasterizer.accumulate_into(&mut coverage);
+    /// ```
+    pub fn accumulate_into(&self, target: &mut [f32]) {
+        let len = self.width * self.height;
+        assert!(
+            target.len() >= len,
+            "target len {} < {len} (width * height)",
+            target.len()
+        );
+        unsafe { (self.accumulate_fn)(&self.a()[..len], &mut target[..len], 0.0, self.fill_rule) };
+    }
+
+    /// Writes pixel coverage for each row `y` into `target[offset + y * stride..][..width]`
+    /// converting into a [`CoveragePixel`] format, e.g. rounded `u8` alpha values.
+    ///
+    /// Allows drawing directly into a larger image, like a glyph atlas texture, without
+    /// a per-pixel callback. Uses SIMD accumulation where available, see
+    /// [`Rasterizer::accumulate_into`].
+    ///
+    /// # Panics
+    /// Panics if `stride` is less than `width` or `target` is too small to hold all rows.
+    ///
+    /// ```
+    /// # use ab_glyph_rasterizer::*;
+    /// # let mut rasterizer = Rasterizer::new(9, 8);
+    /// # rasterizer.draw_line(point(0.0, 0.48), point(1.22, 0.48));
+    /// // draw into an 8-bit 256x256 atlas at position (10, 20)
+    /// let mut atlas = vec![0u8; 256 * 256];
+    /// rasterizer.write_coverage(&mut atlas, 20 * 256 + 10, 256);
+    /// ```
+    pub fn write_coverage<C: CoveragePixel>(&self, target: &mut [C], offset: usize, stride: usize) {
+        const CHUNK: usize = 64;
+        let (w, h) = (self.width, self.height);
+        if w == 0 || h == 0 {
+            return;
+        }
+        assert!(stride >= w, "stride {stride} < width {w}");
+        let required_len = offset + (h - 1) * stride + w;
+        assert!(
+            target.len() >= required_len,
+            "target len {} < {required_len} required to write {w}x{h} at offset {offset}, stride {stride}",
+            target.len(),
+        );
+
+        let mut coverage = [0.0; CHUNK];
+        // note: accumulation carries over from one row to the next
+        let mut acc = 0.0;
+        for (y, row) in self.a()[..w * h].chunks_exact(w).enumerate() {
+            let row_target = &mut target[offset + y * stride..][..w];
+            for (src, dst) in row.chunks(CHUNK).zip(row_target.chunks_mut(CHUNK)) {
+                let coverage = &mut coverage[..src.len()];
+                acc = unsafe { (self.accumulate_fn)(src, coverage, acc, self.fill_rule) };
+                for (px, c) in dst.iter_mut().zip(coverage.iter()) {
+                    *px = C::from_coverage(*c);
+                }
+            }
+        }
+    }
+
     /// Run a callback for each pixel x position, y position & alpha.
     ///
     /// Convenience wrapper for [`Rasterizer::for_each_pixel`].
@@ -296,7 +518,7 @@ impl Rasterizer {
 ///     "Rasterizer { width: 3, height: 4 }"
 /// );
 /// ```
-impl core::fmt::Debug for Rasterizer {
+impl<A> core::fmt::Debug for Rasterizer<A> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Rasterizer")
             .field("width", &self.width)
@@ -305,16 +527,334 @@ impl core::fmt::Debug for Rasterizer {
     }
 }
 
+/// Accumulation grid borrowed from a [`Rasterizer`] for line drawing.
+struct Grid<'a> {
+    width: usize,
+    height: usize,
+    a: &'a mut [f32],
+}
+
+impl Grid<'_> {
+    #[inline(always)] // must inline for simd versions
+    fn draw_line_scalar(&mut self, p0: Point, p1: Point) {
+        self.draw_line_with(p0, p1, add_span_scalar)
+    }
+
+    /// Line drawing logic using `add_span` to accumulate the cells fully
+    /// spanned by the line on each scanline, which simd versions vectorize.
+    #[inline(always)] // must inline for simd versions
+    fn draw_line_with(&mut self, p0: Point, p1: Point, add_span: impl Fn(&mut [f32], f32)) {
+        /// Does `self.a[$lhs] += $rhs` except if $lhs is oob `continue` instead of panic.
+        macro_rules! add_assign_a_idx {
+            ($lhs:expr, $rhs:expr) => {
+                match self.a.get_mut($lhs) {
+                    Some(v) => *v += $rhs,
+                    None => continue,
+                };
+            };
+        }
+
+        if (p0.y - p1.y).abs() <= f32::EPSILON {
+            return;
+        }
+        let (dir, p0, p1) = if p0.y < p1.y {
+            (1.0, p0, p1)
+        } else {
+            (-1.0, p1, p0)
+        };
+        let dxdy = (p1.x - p0.x) / (p1.y - p0.y);
+        let mut x = p0.x;
+        let y0 = p0.y as usize; // note: implicit max of 0 because usize
+        if p0.y < 0.0 {
+            x -= p0.y * dxdy;
+        }
+        for y in y0..self.height.min(p1.y.ceil() as usize) {
+            let linestart = y * self.width;
+            let dy = ((y + 1) as f32).min(p1.y) - (y as f32).max(p0.y);
+            let xnext = x + dxdy * dy;
+            let d = dy * dir;
+            let (x0, x1) = if x < xnext { (x, xnext) } else { (xnext, x) };
+            let x0floor = x0.floor();
+            let x0i = x0floor as i32;
+            let x1ceil = x1.ceil();
+            let x1i = x1ceil as i32;
+            let linestart_x0i = linestart as isize + x0i as isize;
+            if linestart_x0i < 0 {
+                continue;
+            }
+            if x1i <= x0i + 1 {
+                let xmf = 0.5 * (x + xnext) - x0floor;
+                add_assign_a_idx!(linestart_x0i as usize, d - d * xmf);
+                add_assign_a_idx!(linestart_x0i as usize + 1, d * xmf);
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0floor;
+                let a0 = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
+                let x1f = x1 - x1ceil + 1.0;
+                let am = 0.5 * s * x1f * x1f;
+                add_assign_a_idx!(linestart_x0i as usize, d * a0);
+                if x1i == x0i + 2 {
+                    add_assign_a_idx!(linestart_x0i as usize + 1, d * (1.0 - a0 - am));
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    add_assign_a_idx!(linestart_x0i as usize + 1, d * (a1 - a0));
+                    let span_start = linestart_x0i as usize + 2;
+                    let span_end = linestart_x0i as usize + (x1i - x0i - 1) as usize;
+                    match self.a.get_mut(span_start..span_end) {
+                        Some(span) => add_span(span, d * s),
+                        None => {
+                            // partially oob, add to the in-bounds cells then `continue`
+                            let len = self.a.len();
+                            add_span(&mut self.a[span_start.min(len)..], d * s);
+                            continue;
+                        }
+                    }
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    add_assign_a_idx!(
+                        linestart_x0i as usize + (x1i - x0i - 1) as usize,
+                        d * (1.0 - a2 - am)
+                    );
+                }
+                add_assign_a_idx!(linestart_x0i as usize + (x1i - x0i) as usize, d * am);
+            }
+            x = xnext;
+        }
+    }
+}
+
+#[inline(always)]
+fn add_span_scalar(span: &mut [f32], v: f32) {
+    for a in span {
+        *a += v;
+    }
+}
+
+fn draw_line_scalar(grid: &mut Grid<'_>, p0: Point, p1: Point) {
+    grid.draw_line_scalar(p0, p1)
+}
+
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "avx2")]
+unsafe fn draw_line_avx2(grid: &mut Grid<'_>, p0: Point, p1: Point) {
+    grid.draw_line_with(p0, p1, |span, v| add_span_avx2(span, v))
+}
+
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn draw_line_sse4_2(grid: &mut Grid<'_>, p0: Point, p1: Point) {
+    grid.draw_line_with(p0, p1, |span, v| add_span_sse4_2(span, v))
+}
+
+/// Adds `v` to each span cell 8 at a time.
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "avx2")]
+#[inline]
+fn add_span_avx2(span: &mut [f32], v: f32) {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let vv = _mm256_set1_ps(v);
+    let mut chunks = span.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        let ptr = chunk.as_mut_ptr();
+        // safe as chunk has exactly 8 f32s
+        unsafe { _mm256_storeu_ps(ptr, _mm256_add_ps(_mm256_loadu_ps(ptr), vv)) };
+    }
+    add_span_scalar(chunks.into_remainder(), v);
+}
+
+/// Adds `v` to each span cell 4 at a time.
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "sse4.2")]
+#[inline]
+fn add_span_sse4_2(span: &mut [f32], v: f32) {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let vv = _mm_set1_ps(v);
+    let mut chunks = span.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        let ptr = chunk.as_mut_ptr();
+        // safe as chunk has exactly 4 f32s
+        unsafe { _mm_storeu_ps(ptr, _mm_add_ps(_mm_loadu_ps(ptr), vv)) };
+    }
+    add_span_scalar(chunks.into_remainder(), v);
+}
+
+fn accumulate_scalar(src: &[f32], dst: &mut [f32], mut acc: f32, fill_rule: FillRule) -> f32 {
+    for (c, out) in src.iter().zip(dst) {
+        acc += c;
+        *out = fill_rule.coverage(acc);
+    }
+    acc
+}
+
+/// Accumulates 8 cells at a time using an in-register prefix sum.
 #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
 #[target_feature(enable = "avx2")]
-unsafe fn draw_line_avx2(rast: &mut Rasterizer, p0: Point, p1: Point) {
-    rast.draw_line_scalar(p0, p1)
+unsafe fn accumulate_avx2(src: &[f32], dst: &mut [f32], acc: f32, fill_rule: FillRule) -> f32 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let len = src.len().min(dst.len());
+    let (src, dst) = (&src[..len], &mut dst[..len]);
+    let sign_mask = _mm256_set1_ps(-0.0);
+    let (one, two, half) = (
+        _mm256_set1_ps(1.0),
+        _mm256_set1_ps(2.0),
+        _mm256_set1_ps(0.5),
+    );
+    let mut carry = _mm256_set1_ps(acc);
+    let mut chunks = src.chunks_exact(8);
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    for (chunk, out) in (&mut chunks).zip(&mut dst_chunks) {
+        let mut x = _mm256_loadu_ps(chunk.as_ptr());
+        // prefix sum within each 128-bit lane
+        x = _mm256_add_ps(
+            x,
+            _mm256_castsi256_ps(_mm256_slli_si256(_mm256_castps_si256(x), 4)),
+        );
+        x = _mm256_add_ps(
+            x,
+            _mm256_castsi256_ps(_mm256_slli_si256(_mm256_castps_si256(x), 8)),
+        );
+        // add the low lane total to the high lane
+        let lane_totals = _mm256_shuffle_ps(x, x, 0xFF);
+        x = _mm256_add_ps(x, _mm256_permute2f128_ps(lane_totals, lane_totals, 0x08));
+        x = _mm256_add_ps(x, carry);
+        let abs = _mm256_andnot_ps(sign_mask, x);
+        let coverage = match fill_rule {
+            FillRule::NonZero => _mm256_min_ps(abs, one),
+            FillRule::EvenOdd => {
+                let rem = _mm256_sub_ps(
+                    abs,
+                    _mm256_mul_ps(two, _mm256_floor_ps(_mm256_mul_ps(abs, half))),
+                );
+                _mm256_sub_ps(one, _mm256_andnot_ps(sign_mask, _mm256_sub_ps(one, rem)))
+            }
+        };
+        _mm256_storeu_ps(out.as_mut_ptr(), coverage);
+        carry = _mm256_permutevar8x32_ps(x, _mm256_set1_epi32(7));
+    }
+    accumulate_scalar(
+        chunks.remainder(),
+        dst_chunks.into_remainder(),
+        _mm256_cvtss_f32(carry),
+        fill_rule,
+    )
 }
 
+/// Accumulates 4 cells at a time using an in-register prefix sum.
 #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
 #[target_feature(enable = "sse4.2")]
-unsafe fn draw_line_sse4_2(rast: &mut Rasterizer, p0: Point, p1: Point) {
-    rast.draw_line_scalar(p0, p1)
+unsafe fn accumulate_sse4_2(src: &[f32], dst: &mut [f32], acc: f32, fill_rule: FillRule) -> f32 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let len = src.len().min(dst.len());
+    let (src, dst) = (&src[..len], &mut dst[..len]);
+    let sign_mask = _mm_set1_ps(-0.0);
+    let (one, two, half) = (_mm_set1_ps(1.0), _mm_set1_ps(2.0), _mm_set1_ps(0.5));
+    let mut carry = _mm_set1_ps(acc);
+    let mut chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    for (chunk, out) in (&mut chunks).zip(&mut dst_chunks) {
+        let mut x = _mm_loadu_ps(chunk.as_ptr());
+        x = _mm_add_ps(x, _mm_castsi128_ps(_mm_slli_si128(_mm_castps_si128(x), 4)));
+        x = _mm_add_ps(x, _mm_castsi128_ps(_mm_slli_si128(_mm_castps_si128(x), 8)));
+        x = _mm_add_ps(x, carry);
+        let abs = _mm_andnot_ps(sign_mask, x);
+        let coverage = match fill_rule {
+            FillRule::NonZero => _mm_min_ps(abs, one),
+            FillRule::EvenOdd => {
+                let rem = _mm_sub_ps(abs, _mm_mul_ps(two, _mm_floor_ps(_mm_mul_ps(abs, half))));
+                _mm_sub_ps(one, _mm_andnot_ps(sign_mask, _mm_sub_ps(one, rem)))
+            }
+        };
+        _mm_storeu_ps(out.as_mut_ptr(), coverage);
+        carry = _mm_shuffle_ps(x, x, 0xFF);
+    }
+    accumulate_scalar(
+        chunks.remainder(),
+        dst_chunks.into_remainder(),
+        _mm_cvtss_f32(carry),
+        fill_rule,
+    )
+}
+
+/// Accumulates 4 cells at a time using an in-register prefix sum.
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+unsafe fn accumulate_neon(src: &[f32], dst: &mut [f32], acc: f32, fill_rule: FillRule) -> f32 {
+    use core::arch::aarch64::*;
+
+    let len = src.len().min(dst.len());
+    let (src, dst) = (&src[..len], &mut dst[..len]);
+    let zero = vdupq_n_f32(0.0);
+    let (one, two, half) = (vdupq_n_f32(1.0), vdupq_n_f32(2.0), vdupq_n_f32(0.5));
+    let mut carry = vdupq_n_f32(acc);
+    let mut chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    for (chunk, out) in (&mut chunks).zip(&mut dst_chunks) {
+        let mut x = vld1q_f32(chunk.as_ptr());
+        x = vaddq_f32(x, vextq_f32(zero, x, 3));
+        x = vaddq_f32(x, vextq_f32(zero, x, 2));
+        x = vaddq_f32(x, carry);
+        let abs = vabsq_f32(x);
+        let coverage = match fill_rule {
+            FillRule::NonZero => vminq_f32(abs, one),
+            FillRule::EvenOdd => {
+                let rem = vsubq_f32(abs, vmulq_f32(two, vrndmq_f32(vmulq_f32(abs, half))));
+                vsubq_f32(one, vabsq_f32(vsubq_f32(one, rem)))
+            }
+        };
+        vst1q_f32(out.as_mut_ptr(), coverage);
+        carry = vdupq_laneq_f32(x, 3);
+    }
+    accumulate_scalar(
+        chunks.remainder(),
+        dst_chunks.into_remainder(),
+        vgetq_lane_f32(carry, 0),
+        fill_rule,
+    )
+}
+
+/// Return most optimal `AccumulateFn` impl.
+///
+/// With feature `std` on x86/x86_64 will use one-time runtime detection
+/// to pick the best SIMD impl. On aarch64 uses NEON. Otherwise uses a scalar version.
+fn optimal_accumulate_fn() -> AccumulateFn {
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        accumulate_neon
+    }
+    #[cfg(not(all(target_arch = "aarch64", target_feature = "neon")))]
+    unsafe {
+        // safe as write synchronised by Once::call_once or no-write
+        static mut ACCUMULATE_FN: AccumulateFn = accumulate_scalar;
+
+        #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            static INIT: std::sync::Once = std::sync::Once::new();
+            INIT.call_once(|| {
+                // runtime detect optimal simd impls
+                if is_x86_feature_detected!("avx2") {
+                    ACCUMULATE_FN = accumulate_avx2
+                } else if is_x86_feature_detected!("sse4.2") {
+                    ACCUMULATE_FN = accumulate_sse4_2
+                }
+            });
+        }
+
+        ACCUMULATE_FN
+    }
 }
 
 /// Return most optimal `DrawLineFn` impl.
@@ -324,7 +864,7 @@ unsafe fn draw_line_sse4_2(rast: &mut Rasterizer, p0: Point, p1: Point) {
 fn optimal_draw_line_fn() -> DrawLineFn {
     unsafe {
         // safe as write synchronised by Once::call_once or no-write
-        static mut DRAW_LINE_FN: DrawLineFn = Rasterizer::draw_line_scalar;
+        static mut DRAW_LINE_FN: DrawLineFn = draw_line_scalar;
 
         #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
         {
@@ -342,3 +882,318 @@ fn optimal_draw_line_fn() -> DrawLineFn {
         DRAW_LINE_FN
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::point;
+
+    /// Deterministic lines of varying length & slope, some partially or fully out of bounds.
+    fn test_lines() -> impl Iterator<Item = (Point, Point)> {
+        let mut seed = 0x2545_f491_u32;
+        let mut rand = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            (seed % 10_000) as f32 / 10_000.0
+        };
+        (0..2000).map(move |_| {
+            let p0 = point(rand() * 340.0 - 20.0, rand() * 340.0 - 20.0);
+            let p1 = point(rand() * 340.0 - 20.0, rand() * 340.0 - 20.0);
+            (p0, p1)
+        })
+    }
+
+    #[allow(dead_code)] // unused when there are no simd impls
+    fn assert_draw_line_eq_scalar(simd_fn: DrawLineFn) {
+        let len = <Rasterizer>::buffer_len(300, 300);
+        let (mut scalar_a, mut simd_a) = (vec![0.0; len], vec![0.0; len]);
+        let mut scalar = Grid {
+            width: 300,
+            height: 300,
+            a: &mut scalar_a,
+        };
+        let mut simd = Grid {
+            width: 300,
+            height: 300,
+            a: &mut simd_a,
+        };
+
+        for (p0, p1) in test_lines() {
+            scalar.draw_line_scalar(p0, p1);
+            unsafe { simd_fn(&mut simd, p0, p1) };
+        }
+
+        assert!(scalar_a.iter().any(|a| *a != 0.0));
+        for (idx, (s, v)) in scalar_a.iter().zip(&simd_a).enumerate() {
+            assert_eq!(s.to_bits(), v.to_bits(), "unexpected difference at {idx}");
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn draw_line_avx2_eq_scalar() {
+        if is_x86_feature_detected!("avx2") {
+            assert_draw_line_eq_scalar(draw_line_avx2);
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn draw_line_sse4_2_eq_scalar() {
+        if is_x86_feature_detected!("sse4.2") {
+            assert_draw_line_eq_scalar(draw_line_sse4_2);
+        }
+    }
+
+    #[allow(dead_code)] // unused when there are no simd impls
+    fn assert_accumulate_eq_scalar(simd_fn: AccumulateFn) {
+        // closed triangles, so accumulated values stay representative of glyphs
+        let mut rasterizer = Rasterizer::new(300, 300);
+        let mut lines = test_lines().take(150);
+        while let (Some((p0, p1)), Some((p2, _))) = (lines.next(), lines.next()) {
+            rasterizer.draw_line(p0, p1);
+            rasterizer.draw_line(p1, p2);
+            rasterizer.draw_line(p2, p0);
+        }
+        // odd lengths exercise the scalar remainder
+        let cases = [0, 1, 7, 13, 300 * 300]
+            .into_iter()
+            .flat_map(|len| [(len, FillRule::NonZero), (len, FillRule::EvenOdd)]);
+        for (len, fill_rule) in cases {
+            let src = &rasterizer.a[..len];
+            let mut scalar = vec![0.0; len];
+            let mut simd = vec![0.0; len];
+            let scalar_acc = accumulate_scalar(src, &mut scalar, 0.5, fill_rule);
+            let simd_acc = unsafe { simd_fn(src, &mut simd, 0.5, fill_rule) };
+
+            assert!(
+                (scalar_acc - simd_acc).abs() < 1e-3,
+                "{scalar_acc} != {simd_acc}"
+            );
+            for (idx, (s, v)) in scalar.iter().zip(&simd).enumerate() {
+                assert!(
+                    (s - v).abs() < 1e-3,
+                    "unexpected difference at {idx}: {s} != {v}"
+                );
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn accumulate_avx2_eq_scalar() {
+        if is_x86_feature_detected!("avx2") {
+            assert_accumulate_eq_scalar(accumulate_avx2);
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn accumulate_sse4_2_eq_scalar() {
+        if is_x86_feature_detected!("sse4.2") {
+            assert_accumulate_eq_scalar(accumulate_sse4_2);
+        }
+    }
+
+    /// Draw two overlapping 4x4 squares with the same winding direction.
+    fn overlapping_squares(fill_rule: FillRule) -> Rasterizer {
+        let mut rasterizer = Rasterizer::new(6, 4);
+        rasterizer.set_fill_rule(fill_rule);
+        for x in [0.0, 2.0] {
+            rasterizer.draw_line(point(x, 0.0), point(x + 4.0, 0.0));
+            rasterizer.draw_line(point(x + 4.0, 0.0), point(x + 4.0, 4.0));
+            rasterizer.draw_line(point(x + 4.0, 4.0), point(x, 4.0));
+            rasterizer.draw_line(point(x, 4.0), point(x, 0.0));
+        }
+        rasterizer
+    }
+
+    fn row_coverage(rasterizer: &Rasterizer) -> Vec<f32> {
+        let mut row = vec![];
+        rasterizer.for_each_pixel_2d(|_, y, alpha| {
+            if y == 0 {
+                row.push(alpha);
+            }
+        });
+        row
+    }
+
+    #[test]
+    fn fill_rule_non_zero() {
+        let rasterizer = overlapping_squares(FillRule::NonZero);
+        assert_eq!(row_coverage(&rasterizer), [1.0; 6]);
+
+        let mut coverage = vec![0.0; 6 * 4];
+        rasterizer.accumulate_into(&mut coverage);
+        assert_eq!(coverage, [1.0; 6 * 4]);
+    }
+
+    #[test]
+    fn fill_rule_even_odd() {
+        let rasterizer = overlapping_squares(FillRule::EvenOdd);
+        assert_eq!(row_coverage(&rasterizer), [1.0, 1.0, 0.0, 0.0, 1.0, 1.0]);
+
+        let mut coverage = vec![0.0; 6 * 4];
+        rasterizer.accumulate_into(&mut coverage);
+        assert_eq!(&coverage[..6], [1.0, 1.0, 0.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn fill_rule_even_odd_partial_coverage() {
+        assert_eq!(FillRule::EvenOdd.coverage(0.25), 0.25);
+        assert_eq!(FillRule::EvenOdd.coverage(-1.25), 0.75);
+        assert_eq!(FillRule::EvenOdd.coverage(2.5), 0.5);
+        assert_eq!(FillRule::NonZero.coverage(-1.25), 1.0);
+    }
+
+    #[test]
+    fn write_coverage_offset_stride() {
+        let rasterizer = overlapping_squares(FillRule::EvenOdd);
+        let (offset, stride) = (2 * 10 + 3, 10);
+        let mut target = vec![7u8; 10 * 7];
+        rasterizer.write_coverage(&mut target, offset, stride);
+
+        let mut expected = vec![7u8; 10 * 7];
+        rasterizer.for_each_pixel_2d(|x, y, alpha| {
+            expected[offset + y as usize * stride + x as usize] = (alpha * 255.0).round() as u8;
+        });
+        assert_eq!(target, expected);
+    }
+
+    #[test]
+    fn write_coverage_formats() {
+        let mut rasterizer = Rasterizer::new(2, 1);
+        rasterizer.draw_line(point(0.0, 0.0), point(0.5, 1.0));
+        rasterizer.draw_line(point(2.0, 1.0), point(2.0, 0.0));
+
+        let mut u8s = [0u8; 2];
+        rasterizer.write_coverage(&mut u8s, 0, 2);
+        assert_eq!(u8s, [191, 255]);
+
+        let mut u16s = [0u16; 2];
+        rasterizer.write_coverage(&mut u16s, 0, 2);
+        assert_eq!(u16s, [49151, 65535]);
+
+        let mut f32s = [0f32; 2];
+        rasterizer.write_coverage(&mut f32s, 0, 2);
+        assert_eq!(f32s, [0.75, 1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_coverage_target_too_small() {
+        let rasterizer = Rasterizer::new(4, 4);
+        rasterizer.write_coverage(&mut [0u8; 4 * 4], 1, 4);
+    }
+
+    /// Returns the covered area drawing a parabola segment (area `2/3 * 80 * 80`)
+    /// & a circle (area `~PI * 40 * 40`) at a flattening tolerance.
+    fn curve_areas(tolerance: f32) -> (f32, f32) {
+        let area = |rasterizer: &Rasterizer| {
+            let mut sum = 0.0;
+            rasterizer.for_each_pixel(|_, c| sum += c);
+            sum
+        };
+
+        let mut rasterizer = Rasterizer::new(100, 100);
+        rasterizer.set_flattening_tolerance(tolerance);
+        rasterizer.draw_quad(point(10.0, 90.0), point(50.0, -70.0), point(90.0, 90.0));
+        rasterizer.draw_line(point(90.0, 90.0), point(10.0, 90.0));
+        let parabola = area(&rasterizer);
+
+        rasterizer.reset(100, 100);
+        const K: f32 = 40.0 * 0.552_284_8;
+        let c = point(50.0, 50.0);
+        let (r, u) = (point(40.0, 0.0), point(0.0, 40.0));
+        let (kr, ku) = (point(K, 0.0), point(0.0, K));
+        rasterizer.draw_cubic(c + r, c + r + ku, c + u + kr, c + u);
+        rasterizer.draw_cubic(c + u, c + u - kr, c - r + ku, c - r);
+        rasterizer.draw_cubic(c - r, c - r - ku, c - u - kr, c - u);
+        rasterizer.draw_cubic(c - u, c - u + kr, c + r - ku, c + r);
+        let circle = area(&rasterizer);
+
+        (parabola, circle)
+    }
+
+    #[test]
+    fn flattening_tolerance_accuracy() {
+        const PARABOLA: f32 = 2.0 / 3.0 * 80.0 * 80.0;
+        const CIRCLE: f32 = core::f32::consts::PI * 40.0 * 40.0;
+
+        let mut last_err = (f32::MAX, f32::MAX);
+        for tolerance in [4.0, 1.0, <Rasterizer>::DEFAULT_FLATTENING_TOLERANCE, 0.02] {
+            let (parabola, circle) = curve_areas(tolerance);
+            let err = ((parabola - PARABOLA).abs(), (circle - CIRCLE).abs());
+            assert!(
+                err.0 < last_err.0 && err.1 < last_err.1,
+                "error {err:?} should be less than {last_err:?} at tolerance {tolerance}"
+            );
+            last_err = err;
+        }
+        // flattening error at 0.02px is bounded by ~0.02 * perimeter
+        assert!(last_err.0 < 0.02 * 200.0, "{last_err:?}");
+        assert!(last_err.1 < 0.02 * 260.0, "{last_err:?}");
+    }
+
+    /// A cubic with evenly spaced collinear controls is a straight line.
+    #[test]
+    fn draw_cubic_flat_eq_line() {
+        let mut line = Rasterizer::new(20, 20);
+        line.draw_line(point(1.5, 2.0), point(16.5, 18.5));
+        let mut cubic = Rasterizer::new(20, 20);
+        cubic.draw_cubic(
+            point(1.5, 2.0),
+            point(6.5, 7.5),
+            point(11.5, 13.0),
+            point(16.5, 18.5),
+        );
+        assert_eq!(cubic.a, line.a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn flattening_tolerance_zero() {
+        Rasterizer::new(1, 1).set_flattening_tolerance(0.0);
+    }
+
+    #[test]
+    fn with_buffer_eq_new() {
+        let mut alloc = Rasterizer::new(6, 4);
+        // oversized & dirty buffer should be cleared and only partially used
+        let mut buffer = [9.0; <Rasterizer>::buffer_len(6, 4) + 5];
+        let mut borrowed = Rasterizer::with_buffer(6, 4, &mut buffer[..]);
+        borrowed.set_fill_rule(FillRule::EvenOdd);
+        alloc.set_fill_rule(FillRule::EvenOdd);
+
+        for (x0, y0, x1, y1) in [(0.5, 0.5, 4.0, 3.0), (2.0, 1.0, 5.5, 3.5)] {
+            let square = [
+                point(x0, y0),
+                point(x1, y0),
+                point(x1, y1),
+                point(x0, y1),
+                point(x0, y0),
+            ];
+            for w in square.windows(2) {
+                alloc.draw_line(w[0], w[1]);
+                borrowed.draw_line(w[0], w[1]);
+            }
+        }
+
+        let mut expected = [0.0; 6 * 4];
+        let mut actual = [0.0; 6 * 4];
+        alloc.accumulate_into(&mut expected);
+        borrowed.accumulate_into(&mut actual);
+        assert_eq!(actual, expected);
+        assert!(actual.iter().any(|c| *c != 0.0));
+
+        assert_eq!(buffer[<Rasterizer>::buffer_len(6, 4)..], [9.0; 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_buffer_too_small() {
+        let mut buffer = [0.0; 6 * 4];
+        Rasterizer::with_buffer(6, 4, &mut buffer[..]);
+    }
+}